@@ -1,26 +0,0 @@
-if (!window.flical) window.flical = {}
-
-window.flical.keys = {
-  "a": "A",   "b": "B",   "c": "C",   "d": "D",   "e": "E",   "f": "F",
-  "Enter": "ENTER",  "S": "STO",       "R": "RCL",        "Bspc": "DEL", 
-  "+": "ADD",        "7": "7",         "8": "8",          "9": "9",
-  "-": "SUB",        "4": "4",         "5": "5",          "6": "6",
-  "*": "MUL",        "1": "1",         "2": "2",          "3": "3",
-  "/": "DIV",        ".": "DOT",       "0": "0",          "A": "ALT",
-
-  "D": "DISP",       "A-y": "Y_X",     "Up": "R_UP",      "u": "UNDO", 
-  "A-+": "EDATA",    "A-s": "SIN",     "A-c": "COS",      "A-t": "TAN",
-  "A--": "CHS",      "A-n": "LN",      "A-d": "LD",       "A-l": "LOG",
-  "!": "!",          "r": "ROOT",      "v": "SQRT",       "A-h": "TO_HMS",
-  "A-/": "RECIP",    "A-i": "INT",     "i": "I",          "I": "INV",
-  
-  "M": "MACRO",      "A-z": "Z_X",     "Down": "R_DOWN",
-  "A-r": "RAND",     "A-S": "ASIN",    "A-C": "ACOS",     "A-T": "ATAN",
-  "A-a": "ABS",      "A-N": "EXP",     "A-b": "LB",       "H": "H",
-  "A-P": "DPERC",    "^": "POW",       "C-2": "POW2",     "A-H": "TO_H",
-  "p": "PERC",       "A-f": "FRAC",    "[": "ROUND",      "Esc": "BASE",
- 
-  "Tab": "META",
-}
-
-// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
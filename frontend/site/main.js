@@ -1,4 +1,5 @@
 document.addEventListener("keydown", handleKey);
+document.addEventListener("paste", handlePaste);
 window.addEventListener("load", _ => {
   scaleCalculatorToViewport()
 
@@ -58,10 +59,17 @@ function replaceUnderscores(screen) {
 function handleKey(ev) {
   let prefixes = (ev.ctrlKey ? "C-" : "") + (ev.altKey ? "A-" : "")
   let key = prefixes + ev.key.replace(/^Arrow/, "").replace("/^Escape$", "Esc")
+  if (key == "C-v") return // leave it to the paste event
+
   wasmBindings.flical_command(wasmBindings.flical_translate_key_press(key))
   ev.preventDefault()
 }
 
+function handlePaste(ev) {
+  wasmBindings.flical_paste(ev.clipboardData.getData("text"))
+  ev.preventDefault()
+}
+
 Array.from(document.querySelectorAll("button")).map(
   (button, index) => {
     let touch = _ => touched(button, index)
@@ -11,26 +11,41 @@ fn main() {
   init_panic_hook();
   log("Rust panic hook initialized");
 
-  let js_calls = rpn::JsCalls { log, lang, key };
-  putFlicalSingleton(Flical(rpn::Calc::new(js_calls)));
+  let js_calls = rpn::JsCalls { log, store_config: storeConfig };
+  let mut calc = rpn::Calc::new(js_calls);
+  calc.lang = rpn::Lang::from_code(&language()).unwrap_or_default();
+  if let Err(err) = calc.load_config(&loadConfig()) {
+    log(&format!("Ignoring stored configuration: {err}"));
+  }
+  putFlicalSingleton(Flical(calc));
 }
 
+// The configuration is stored in the local storage of the browser
 #[wasm_bindgen(inline_js = "
-export function key(key) { 
-  return window.flical?.keys?.[key] ?? ''
+export function storeConfig(config) {
+  window.localStorage?.setItem('flical.config', config)
 }
 ")]
 extern "C" {
-  pub fn key(key: &str) -> String;
+  pub fn storeConfig(config: &str);
 }
 
 #[wasm_bindgen(inline_js = "
-export function lang(lang, id) { 
-  return window.flical?.lang?.[lang]?.[id] ?? ''
+export function loadConfig() {
+  return window.localStorage?.getItem('flical.config') ?? ''
 }
 ")]
 extern "C" {
-  pub fn lang(lang: &str, id: &str) -> String;
+  pub fn loadConfig() -> String;
+}
+
+#[wasm_bindgen(inline_js = "
+export function language() {
+  return window.navigator?.language ?? ''
+}
+")]
+extern "C" {
+  pub fn language() -> String;
 }
 
 #[wasm_bindgen(inline_js = "
@@ -112,4 +127,12 @@ pub fn flical_command(command: String) {
   }
 }
 
+#[wasm_bindgen]
+pub fn flical_paste(text: String) {
+  let mut flical = takeFlicalSingleton();
+  flical.0.paste(&text);
+  updateScreen(flical.0.display().into());
+  putFlicalSingleton(flical);
+}
+
 // Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -1,22 +1,58 @@
 use std::{fmt, mem};
 
+use crate::catalog::CATEGORIES;
+use crate::config::{Config, ConfigError, Layer, MODE_BUTTON};
+use crate::context::{Context, ContextGuard, MAX_DENOMS, PRECISIONS};
+use crate::entry::{self, EntryError};
+use crate::parse;
+use crate::text::{break_number, fit, fit_end, NUM_WIDTH, WIDTH};
+use crate::unit::{Unit, UnitError};
+use crate::Lang;
 use crate::Num;
 use crate::NumDisplay::{self, *};
 use pretty::pretty;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq)] #[rustfmt::skip]
-pub enum DispState { #[default] DispStart, DispFix, DispSci, DispHex }
+pub enum DispState {
+  #[default] DispStart, DispFix, DispSci, DispEng, DispEngSi, DispHex,
+}
 use DispState::*;
 
+/// CATALOG: choose a category (CatalogStart) then its conversion of x
+/// (CatalogCategory) with the buttons A to F
+#[derive(Copy, Clone, Debug, Default, PartialEq)] #[rustfmt::skip]
+pub enum CatalogState { #[default] CatalogStart, CatalogCategory(u8) }
+use CatalogState::*;
+
+/// CONF: choose a button (ConfButton) then its new command (ConfCommand), or
+/// a key (ConfKey) then its new command (ConfKeyCommand). The last layer is
+/// the layer of the buttons to pick the new command from.
 #[derive(Copy, Clone, Debug, Default, PartialEq)] #[rustfmt::skip]
-pub enum State { #[default] Base, Alt, Inv, Sto, Rcl, Disp(DispState) }
+pub enum ConfState {
+  #[default] ConfStart, ConfButton(Layer), ConfCommand(Layer, u8, Layer),
+  ConfKey, ConfKeyCommand(Layer),
+}
+use ConfState::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)] #[rustfmt::skip]
+pub enum State {
+  #[default] Base, Alt, Inv, Sto, Rcl, Disp(DispState), Conf(ConfState),
+  Catalog(CatalogState),
+}
 use State::*;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct JsCalls {
   pub log: fn(&str),
-  pub lang: fn(&str, &str) -> String,
-  pub key: fn(&str) -> String,
+  pub store_config: fn(&str),
+}
+
+// The callbacks are no state of the calculator, and comparing fn pointers
+// isn't meaningful
+impl PartialEq for JsCalls {
+  fn eq(&self, _: &Self) -> bool {
+    true
+  }
 }
 
 #[derive(Clone, PartialEq)]
@@ -25,20 +61,30 @@ pub struct Calc {
   pub z: Num,
   pub y: Num,
   pub x: Num,
+  pub last_x: Num,
   pub input: String,
+  pub cursor: usize,
   pub state: State,
   pub disp: NumDisplay,
   pub text: String,
   pub scroll: usize,
+  pub config: Config,
+  pub conf_key: String,
+  pub lang: Lang,
   pub js_calls: JsCalls,
+  context: ContextGuard,
 }
 
 impl fmt::Debug for Calc {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    let Calc { t, z, y, x, input, state: meta, disp, text, scroll, .. } = self;
+    let Calc {
+      t, z, y, x, input, cursor, state: meta, disp, text, scroll, ..
+    } = self;
     let stack = format!("t {t:?} z {z:?} y {y:?} x {x:?}");
     let text = pretty(text.as_bytes(), 30);
-    let text = format!("{meta:?} {disp:?} `{input}` `{text}` scroll {scroll}");
+    let text = format!(
+      "{meta:?} {disp:?} `{input}` cursor {cursor} `{text}` scroll {scroll}"
+    );
 
     write!(f, "Calc {{\n  {stack}\n  {text}\n}}")
   }
@@ -47,22 +93,42 @@ impl fmt::Debug for Calc {
 impl Calc {
   pub fn new(js_calls: JsCalls) -> Self {
     let zero = Num::default();
+    let config = Config::default();
     Calc {
       t: zero,
       z: zero,
       y: zero,
       x: zero,
+      last_x: zero,
       input: String::new(),
+      cursor: 0,
       state: Base,
       disp: Std,
       text: "".into(),
       scroll: 0,
+      context: ContextGuard::new(config.context),
+      config,
+      conf_key: String::new(),
+      lang: Lang::default(),
       js_calls,
     }
   }
 
+  /// Load the user configuration, see `Config::load()`, and switch to its
+  /// language if there's one.
+  pub fn load_config(&mut self, text: &str) -> Result<(), ConfigError> {
+    self.config.load(text)?;
+    self.install_context();
+    if let Some(lang) = self.config.lang {
+      self.lang = lang;
+    }
+
+    Ok(())
+  }
+
   pub fn display(&self) -> String {
     self.log(&format!("{self:?}"));
+    self.install_context();
 
     let meta = match self.state {
       Alt => "ALT",
@@ -76,36 +142,32 @@ impl Calc {
     let lines_n = lines.len();
     let scroll = self.scroll.min(lines_n - 1);
 
+    // Lines never exceed the display width, see `text::fit()`
     let disp = self.disp;
     let shows = |i| scroll >= i && lines_n > i;
-    let check_line_len = |s: &str| {
-      let n = s.chars().count();
-      if n > 35 {
-        self.log(&format!("Warning: `{s}` has len {n}, max. 35 is ok"));
+    let line = |i: usize, number: String| {
+      if shows(i) {
+        fit(lines[scroll - i], WIDTH)
+      } else {
+        number
       }
     };
 
-    let t = format!("{: <29} {meta}", self.t.disp(disp));
-    let t = if shows(4) { lines[scroll - 4] } else { &t };
-    check_line_len(t);
-
-    let z = format!("{: <33}", self.z.disp(disp));
-    let z = if shows(3) { lines[scroll - 3] } else { &z };
-    check_line_len(z);
-
-    let y = format!("{: <33}", self.y.disp(disp));
-    let y = if shows(2) { lines[scroll - 2] } else { &y };
-    check_line_len(z);
+    let t = line(4, format!("{} {meta}", fit(&self.t.disp(disp), 29)));
+    let z = line(3, fit(&self.z.disp(disp), NUM_WIDTH));
+    let y = line(2, fit(&self.y.disp(disp), NUM_WIDTH));
 
-    let empty = self.input.is_empty();
-    let i = &self.input;
-    let i = format!("{i}_");
-    let x = self.x.disp(disp);
-    let x = if empty { format!("{x: <33}") } else { format!("› {i:33}") };
-    let x = if shows(1) { lines[scroll - 1] } else { &x };
-    check_line_len(x);
+    let x = if self.input.is_empty() {
+      fit(&self.x.disp(disp), NUM_WIDTH)
+    } else {
+      // Keep the cursor in view and cut the input after it if too long
+      let (before, after) = self.input.split_at(self.input_pos(self.cursor));
+      let before = fit_end(&format!("{before}_"), NUM_WIDTH);
+      format!("› {}", fit(&format!("{}{after}", before.trim_end()), NUM_WIDTH))
+    };
+    let x = line(1, x);
 
-    let s = if shows(0) { lines[scroll] } else { "" };
+    let s = line(0, String::new());
 
     format!("{t}\n{z}\n{y}\n{x}\n{s}")
   }
@@ -123,16 +185,91 @@ impl Calc {
     self.z = self.t;
   }
 
+  /// Insert into the input at the cursor, a new input pushes the stack up
   pub fn add_input(&mut self, input: &str) {
-    if self.input.is_empty() {
-      self.up_with_x(Num::ZERO)
+    let cursor = if self.input.is_empty() { 0 } else { self.cursor };
+    let mut edited = self.input.clone();
+    edited.insert_str(self.input_pos(cursor), input);
+    self.edit_input(edited, cursor + input.chars().count());
+  }
+
+  /// Add a digit, in the hex display modes a new input starts with `0x`
+  pub fn add_digit(&mut self, digit: char) {
+    let hex = matches!(self.disp, HexU | HexL) && self.input.is_empty();
+    self.add_input(&format!("{}{digit}", if hex { "0x" } else { "" }));
+  }
+
+  /// Replace the input and move the cursor to its end
+  pub fn set_input(&mut self, input: String) {
+    let cursor = input.chars().count();
+    self.edit_input(input, cursor);
+  }
+
+  // Every edit of the input goes through the entry state machine, if it
+  // rejects the edited input the input stays and the status tells why
+  fn edit_input(&mut self, input: String, cursor: usize) {
+    if let Err(err) = entry::check(&input) {
+      self.entry_error(err);
+      return;
+    }
+    if self.input.is_empty() && !input.is_empty() {
+      self.up_with_x(Num::ZERO);
+    }
+    self.input = input;
+    self.cursor = cursor;
+  }
+
+  /// Change the sign of the number being entered like on HP calculators: the
+  /// sign of the exponent if one is being entered, else of the mantissa. For
+  /// complex numbers and the polar form the sign of the part after `i` or `∠`.
+  pub fn chs_input(&mut self) {
+    let mut chars = self.input.char_indices().rev();
+    let part = chars.find(|&(_, c)| matches!(c, 'i' | '∠'));
+    let start = part.map_or(0, |(i, c)| i + c.len_utf8());
+    let pos = match self.input[start..].rfind('e') {
+      Some(e) => start + e + 1,
+      None => start,
+    };
+
+    let at = self.input[..pos].chars().count();
+    let mut input = self.input.clone();
+    let cursor = if input[pos..].starts_with('-') {
+      input.remove(pos);
+      if at < self.cursor {
+        self.cursor - 1
+      } else {
+        self.cursor
+      }
+    } else {
+      input.insert(pos, '-');
+      if at <= self.cursor {
+        self.cursor + 1
+      } else {
+        self.cursor
+      }
+    };
+    self.edit_input(input, cursor);
+  }
+
+  /// Delete the character before the cursor
+  pub fn del_input(&mut self) {
+    if self.cursor > 0 {
+      let mut input = self.input.clone();
+      input.remove(self.input_pos(self.cursor - 1));
+      self.edit_input(input, self.cursor - 1);
     }
-    self.input.push_str(input);
+  }
+
+  // The cursor is a char index, this is its byte index into the input
+  fn input_pos(&self, cursor: usize) -> usize {
+    let mut chars = self.input.char_indices();
+    chars.nth(cursor).map_or(self.input.len(), |(i, _)| i)
   }
 
   /// DISP spans up a state machine consisting of Dsp* states.
   /// Handle DISP by switching between these states, for example in DispStart
-  /// A sets display mode to Std, B switches to DspFix state, etc.
+  /// A sets display mode to Std, B switches to DspFix state, etc. In DispFix
+  /// D sets Frac, in DispSci D and E lead to DispEng and DispEngSi.
   pub fn handle_disp(&mut self, command: &str) -> bool {
     let disp_state = if let Disp(disp_state) = self.state {
       disp_state
@@ -160,7 +297,12 @@ impl Calc {
       (DispStart, b"D") => self.state = Disp(DispHex),
       (DispStart, b"E") => set_display(Raw),
       (DispFix, [b'0'..=b'9']) => set_display(Fix(digit)),
+      (DispFix, b"D") => set_display(Frac),
       (DispSci, [b'0'..=b'9']) => set_display(Sci(digit)),
+      (DispSci, b"D") => self.state = Disp(DispEng),
+      (DispSci, b"E") => self.state = Disp(DispEngSi),
+      (DispEng, [b'0'..=b'9']) => set_display(Eng(digit)),
+      (DispEngSi, [b'0'..=b'9']) => set_display(EngSi(digit)),
       (DispHex, b"A") => set_display(HexU),
       (DispHex, b"B") => set_display(HexL),
       (_, b"F") => action = Current,
@@ -171,14 +313,22 @@ impl Calc {
 
     let disp_state = if let Disp(d) = self.state { d } else { DispStart };
     let status = match (disp_state, action) {
-      (DispFix | DispSci, Stay) => "Enter digit for precision      Show".into(),
-      (DispHex, Stay) => "Upper Lower                    Show".into(),
-      (_, Set) => format!("Display set to: {:?}", self.disp),
-      (_, Current) => format!("Display is: {:?}", self.disp),
-      (_, Error) => format!("Bad key? Display is: {:?}", self.disp),
+      (DispSci, Stay) => self.msg("disp_sci_menu", &[]),
+      (DispFix, Stay) => self.msg("disp_fix_menu", &[]),
+      (DispEng | DispEngSi, Stay) => self.msg("disp_digit", &[]),
+      (DispHex, Stay) => self.msg("disp_hex", &[]),
+      (_, Set) => self.msg("disp_set", &[&self.lang.disp_name(self.disp)]),
+      (_, Current) => self.msg("disp_is", &[&self.lang.disp_name(self.disp)]),
+      (_, Error) => {
+        self.msg("disp_bad_key", &[&self.lang.disp_name(self.disp)])
+      }
       (_, _) => unreachable!(),
     };
-    self.status(&status);
+    if action == Current {
+      self.show_x(&status);
+    } else {
+      self.status(&status);
+    }
 
     if action != Stay {
       self.state = Base;
@@ -187,11 +337,173 @@ impl Calc {
     true
   }
 
+  /// Show x in full precision on the lines below the status, scrolled with 0
+  /// and 2 like the help, see `NumDisplay::Full`
+  pub fn show_x(&mut self, status: &str) {
+    let x = break_number(&self.x.disp(Full));
+    self.status(&format!("{status}\n{x}"));
+    self.scroll = self.text.split('\n').count().min(5) - 1;
+  }
+
+  /// CATALOG spans up a state machine like DISP. In CatalogStart A to F pick
+  /// a category and show its conversions on two lines, in CatalogCategory A
+  /// to F convert x in place and keep the old x in LAST x.
+  pub fn handle_catalog(&mut self, command: &str) -> bool {
+    let catalog_state = if let Catalog(catalog_state) = self.state {
+      catalog_state
+    } else {
+      return false;
+    };
+    let index = match command.as_bytes() {
+      [c @ b'A'..=b'F'] => Some(c - b'A'),
+      _ => None,
+    };
+
+    self.state = Base;
+    match (catalog_state, index) {
+      (CatalogStart, Some(index)) => {
+        self.state = Catalog(CatalogCategory(index));
+        self.status(&CATEGORIES[index as usize].menu());
+        self.scroll = 1;
+      }
+      (CatalogCategory(_), Some(_)) if self.x.is_p() => {
+        self.status_msg("err_catalog_unit", &[])
+      }
+      (CatalogCategory(category), Some(index)) => {
+        let category = &CATEGORIES[category as usize];
+        let (from, to) = category.units(index as usize);
+        self.last_x = self.x;
+        self.x = category.convert(index as usize, self.x);
+        self.status_msg("catalog_done", &[from, to]);
+      }
+      (_, None) => self.status_msg("catalog_bad_key", &[]),
+    }
+
+    self.log(&format!("state {catalog_state:?} now {:?}", self.state));
+    true
+  }
+
+  /// CONF spans up a state machine consisting of Conf* states like DISP. In
+  /// the states picking a button the buttons send `_BUTTON_{index}` and in
+  /// ConfKey the keys send `_KEY_{key}` (see `translate_button_press()` and
+  /// `translate_key_press()`).
+  pub fn handle_conf(&mut self, command: &str) -> bool {
+    let conf_state = if let Conf(conf_state) = self.state {
+      conf_state
+    } else {
+      return false;
+    };
+    let button = command.strip_prefix("_BUTTON_").and_then(|i| i.parse().ok());
+    let key = command.strip_prefix("_KEY_");
+
+    let status = match (conf_state, button, key) {
+      (ConfStart, ..) => match command {
+        "A" => self.conf(ConfButton(Layer::Base)),
+        "B" => self.conf(ConfButton(Layer::Alt)),
+        "C" => self.conf(ConfButton(Layer::Inv)),
+        "D" => self.conf(ConfKey),
+        "E" => {
+          self.config = Config::default();
+          (self.js_calls.store_config)("");
+          self.msg("conf_reset", &[])
+        }
+        "F" => {
+          self.lang = self.lang.next();
+          self.config.lang = Some(self.lang);
+          (self.js_calls.store_config)(&self.config.to_text());
+          self.msg("conf_lang", &[&self.msg("lang_name", &[])])
+        }
+        _ => self.msg("conf_bad_key", &[]),
+      },
+      (ConfButton(_), Some(MODE_BUTTON), _) => {
+        self.msg("conf_mode_button", &[])
+      }
+      (ConfButton(layer), Some(index), _) => {
+        self.conf(ConfCommand(layer, index, Layer::Base))
+      }
+      (ConfCommand(layer, index, from), Some(MODE_BUTTON), _) => {
+        self.conf(ConfCommand(layer, index, from.next()))
+      }
+      (ConfCommand(layer, index, from), Some(from_index), _) => {
+        let command = self.config.button(from, from_index).to_owned();
+        self.conf_set_button(layer, index, &command)
+      }
+      (ConfCommand(layer, index, _), None, None) if is_command(command) => {
+        self.conf_set_button(layer, index, command)
+      }
+      (ConfKey, _, Some(key)) => {
+        self.conf_key = key.into();
+        self.conf(ConfKeyCommand(Layer::Base))
+      }
+      (ConfKeyCommand(from), Some(MODE_BUTTON), _) => {
+        self.conf(ConfKeyCommand(from.next()))
+      }
+      (ConfKeyCommand(from), Some(index), _) => {
+        let command = self.config.button(from, index).to_owned();
+        let key = mem::take(&mut self.conf_key);
+        match self.config.keymap.bind(&key, &command) {
+          Ok(()) => {
+            (self.js_calls.store_config)(&self.config.to_text());
+            self.msg("conf_key_set", &[&key, &command])
+          }
+          Err(err) => self.msg("conf_error", &[&err.0]),
+        }
+      }
+      (_, _, _) => self.msg("conf_bad_key", &[]),
+    };
+
+    self.log(&format!("state {conf_state:?} status {status}"));
+    self.status(&status);
+
+    // Stay only if conf() switched to another ConfState
+    if self.state == Conf(conf_state) {
+      self.state = Base;
+    }
+
+    true
+  }
+
+  fn conf_set_button(
+    &mut self,
+    layer: Layer,
+    index: u8,
+    command: &str,
+  ) -> String {
+    match self.config.set_button(layer, index, command) {
+      Ok(()) => {
+        (self.js_calls.store_config)(&self.config.to_text());
+        self.msg("conf_button_set", &[command])
+      }
+      Err(err) => self.msg("conf_error", &[&err.0]),
+    }
+  }
+
+  // Switch to the ConfState and return its status line
+  fn conf(&mut self, conf_state: ConfState) -> String {
+    self.state = Conf(conf_state);
+    match conf_state {
+      ConfStart => self.msg("conf_menu", &[]),
+      ConfButton(layer) => self.msg("conf_button", &[layer.label()]),
+      ConfCommand(layer, index, from) => {
+        let command = self.config.button(layer, index);
+        self.msg("conf_command", &[command, from.label()])
+      }
+      ConfKey => self.msg("conf_key", &[]),
+      ConfKeyCommand(from) => {
+        self.msg("conf_key_command", &[&self.conf_key, from.label()])
+      }
+    }
+  }
+
   /// Handle command, return true to flash
   pub fn handle_command(&mut self, command: &str) -> bool {
     self.log(&format!("Command `{command}`"));
+    self.install_context();
 
-    if self.handle_disp(command) {
+    if self.handle_disp(command)
+      || self.handle_conf(command)
+      || self.handle_catalog(command)
+    {
       return true;
     }
 
@@ -213,14 +525,36 @@ impl Calc {
     self.status("");
 
     if command.ends_with("_long") {
-      self.text = (self.js_calls.lang)("en", command);
+      let command =
+        if command.starts_with("UNIT_") { "UNIT_long" } else { command };
+      self.text = self.lang.help(command);
       self.scroll = if command == "ENTER_long" { 4 } else { 1 };
       return true;
     }
 
-    // For commands in '0' ... '9' handle here, saves space in COMMANDS
-    if let Some(c @ '0'..='9') = command.chars().next() {
-      self.add_input(&c.to_string());
+    // For commands in '0' ... '9' handle here, saves space in COMMANDS, and in
+    // the hex display modes also for the hex digits 'A' ... 'F'
+    let hex = matches!(self.disp, HexU | HexL) && command.len() == 1;
+    match command.chars().next() {
+      Some(c @ '0'..='9') => {
+        self.add_digit(c);
+        return true;
+      }
+      Some(c @ 'A'..='F') if hex => {
+        self.add_digit(c);
+        return true;
+      }
+      _ => (),
+    }
+
+    // Units like UNIT_km/h, attach the unit to x or convert x to the unit
+    if let Some(unit) = command.strip_prefix("UNIT_") {
+      COMMANDS["_INPUT_X"](self);
+      COMMANDS["BASE"](self);
+      match self.x.with_unit(unit) {
+        Ok(x) => self.x = x,
+        Err(err) => self.unit_error(err),
+      }
       return true;
     }
 
@@ -234,27 +568,119 @@ impl Calc {
     false
   }
 
+  /// Push the number in the pasted text, see `parse::number()`, else show
+  /// the column where the text isn't a number
+  pub fn paste(&mut self, text: &str) {
+    self.log(&format!("Paste `{text}`"));
+    self.install_context();
+    COMMANDS["_INPUT_X"](self);
+    COMMANDS["BASE"](self);
+    match parse::number(text) {
+      Ok(x) => self.up_with_x(x),
+      Err(err) => {
+        let column = text[..err.span.start].chars().count() + 1;
+        self.status_msg("err_paste", &[&column.to_string()]);
+      }
+    }
+  }
+
+  // Install the context again, another calculator may have installed its own
+  fn install_context(&self) {
+    self.context.install(self.config.context);
+  }
+
+  /// Switch to the context and store it in the configuration. The numbers on
+  /// the stack are converted, for example reals to decimals.
+  pub fn set_context(&mut self, context: Context) {
+    self.config.context = context;
+    self.install_context();
+    for num in [&mut self.t, &mut self.z, &mut self.y, &mut self.x] {
+      *num = num.to_native().into();
+    }
+    (self.js_calls.store_config)(&self.config.to_text());
+  }
+
+  /// Check the units of y op x, else set the status to the error
+  pub fn check_units(&mut self, op: &str) -> bool {
+    let checked = self.y.check_units(op, self.x);
+    checked.map_err(|err| self.unit_error(err)).is_ok()
+  }
+
+  /// Check that the numbers have no units for functions like SIN, else set
+  /// the status to the error
+  pub fn check_plain(&mut self, nums: &[Num]) -> bool {
+    let checked = nums.iter().try_for_each(|num| num.check_plain());
+    checked.map_err(|err| self.unit_error(err)).is_ok()
+  }
+
+  /// Set the status to the unit error in the current language
+  pub fn unit_error(&mut self, err: UnitError) {
+    match err {
+      UnitError::Unknown(unit) => self.status_msg("err_unit", &[&unit]),
+      UnitError::Mismatch(a, b) => self.status_msg("err_units", &[&a, &b]),
+      UnitError::TooLong => self.status_msg("err_unit_long", &[]),
+      UnitError::Overflow => self.status_msg("err_unit_exp", &[]),
+      UnitError::Plain(unit) => self.status_msg("err_unit_plain", &[&unit]),
+      UnitError::Power(unit) => self.status_msg("err_unit_power", &[&unit]),
+    }
+  }
+
+  /// Set the status to the reason why the entry state machine rejected a key
+  pub fn entry_error(&mut self, err: EntryError) {
+    let c = |c: char| c.to_string();
+    match err {
+      EntryError::Misplaced(ch) => self.status_msg("err_misplaced", &[&c(ch)]),
+      EntryError::Duplicate(ch) => self.status_msg("err_duplicate", &[&c(ch)]),
+      EntryError::ExpFraction => self.status_msg("err_e_fraction", &[]),
+      EntryError::ExpDigits => self.status_msg("err_exp_digits", &[]),
+      EntryError::Numerator => self.status_msg("err_numerator", &[]),
+      EntryError::Denominator => self.status_msg("err_denominator", &[]),
+      EntryError::Fraction => self.status_msg("err_fraction", &[]),
+      EntryError::Pm => self.status_msg("err_pm", &[]),
+      EntryError::Angle => self.status_msg("err_angle", &[]),
+      EntryError::PolarInfinity => self.status_msg("err_polar_inf", &[]),
+      EntryError::HexDigits => self.status_msg("err_hex_digits", &[]),
+    }
+  }
+
+  /// The message in the current language, see `Lang::msg()`
+  pub fn msg(&self, id: &str, args: &[&str]) -> String {
+    self.lang.msg(id, args)
+  }
+
+  /// Set the status to the message in the current language
+  pub fn status_msg(&mut self, id: &str, args: &[&str]) {
+    self.status(&self.msg(id, args));
+  }
+
   pub fn status(&mut self, status: &str) {
     self.scroll = 0;
     self.text = status.into();
   }
 
-  pub fn button_from(buttons: &[&'static str], index: u8) -> &'static str {
-    buttons.get(index as usize).copied().unwrap_or_default()
-  }
-
   pub fn translate_button_press(&self, index: u8, long: bool) -> String {
-    let command = match &self.state {
-      Alt => Self::button_from(ALT_BUTTONS, index),
-      Inv => Self::button_from(INV_BUTTONS, index),
-      _ => Self::button_from(BASE_BUTTONS, index),
+    // Menus use the default buttons A to F, even if the user changed them
+    let layer = match &self.state {
+      Disp(_) | Catalog(_) | Conf(ConfStart | ConfKey) => {
+        let buttons = Layer::Base.default_buttons();
+        return buttons.get(index as usize).copied().unwrap_or_default().into();
+      }
+      Conf(_) => return format!("_BUTTON_{index}"),
+      Alt => Layer::Alt,
+      Inv => Layer::Inv,
+      _ => Layer::Base,
     };
+    let command = self.config.button(layer, index);
 
     format!("{command}{}", if long { "_long" } else { "" })
   }
 
   pub fn translate_key_press(&self, key: String) -> String {
-    (self.js_calls.key)(&key)
+    if self.state == Conf(ConfKey) {
+      return format!("_KEY_{key}");
+    }
+
+    self.config.keymap.get(&key).unwrap_or_default().into()
   }
 
   pub fn log(&self, msg: &str) {
@@ -262,6 +688,19 @@ impl Calc {
   }
 }
 
+/// Is the command known? Known commands are the digits, the commands in
+/// COMMANDS, the units like `UNIT_km/h` and the commands on the buttons (even
+/// if not yet implemented).
+pub fn is_command(command: &str) -> bool {
+  let on_buttons = |buttons: &[&str]| buttons.contains(&command);
+  let unit = command.strip_prefix("UNIT_");
+
+  matches!(command.as_bytes(), [b'0'..=b'9'])
+    || COMMANDS.contains_key(command)
+    || unit.is_some_and(|unit| Unit::parse(unit).is_ok())
+    || [BASE_BUTTONS, ALT_BUTTONS, INV_BUTTONS].into_iter().any(on_buttons)
+}
+
 #[rustfmt::skip]
 pub static BASE_BUTTONS: &[&str] = &[
   "A",       "B",       "C",       "D",       "E",       "F",
@@ -274,7 +713,7 @@ pub static BASE_BUTTONS: &[&str] = &[
 
 #[rustfmt::skip]
 pub static ALT_BUTTONS: &[&str] = &[
-  "ALT_A",   "ALT_B",   "ALT_C",   "ALT_D",   "ALT_E",   "ALT_F",
+  "CATALOG", "LAST_X",  "ANGLE",   "TO_POLAR","RE",      "ARG",
   "DISP",    "XY",      "R_DOWN",  "UNDO",
   "EDATA",   "SIN",     "COS",     "TAN",
   "CHS",     "LN",      "LD",      "LOG",
@@ -284,7 +723,7 @@ pub static ALT_BUTTONS: &[&str] = &[
 
 #[rustfmt::skip]
 pub static INV_BUTTONS: &[&str] = &[
-  "INV_A",   "INV_B",   "INV_C",   "INV_D",   "INV_E",   "INV_F",
+  "POLAR",   "CONJ",    "DEG",     "TO_RECT", "IM",      "CONF",
   "MACRO",   "XZ",      "R_UP",    "REDO",
   "RAND",    "ASIN",    "ACOS",    "ATAN",
   "ABS",     "EXP",     "LB",      "H",
@@ -323,16 +762,11 @@ macro_rules! commands {
 // to name1(calc); ... namen(calc); invocations bevore the main code.
 pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
   "E" => fn e(calc: &mut Calc) base {
-    if calc.input.contains('/') {
-      calc.status("Error: No 'e' for fractions");
-      return;
-    }
-    let pos = calc.input.find('i').unwrap_or_default();
-    if calc.input[pos..].contains('e') {
-      calc.status("Error: Duplicate 'e'");
-      return;
+    if matches!(calc.disp, HexU | HexL) {
+      calc.add_digit('E');
+    } else {
+      calc.add_input("e");
     }
-    calc.add_input("e")
   }
 
   "ENTER" => fn enter(calc: &mut Calc) base {
@@ -345,7 +779,12 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
 
   "DISP" => fn disp(calc: &mut Calc) {
     calc.state = Disp(DispStart);
-    calc.status(" Std   Fix   Sci   Hex   Raw   Show");
+    calc.status_msg("disp_menu", &[]);
+  }
+
+  "CONF" => fn conf(calc: &mut Calc) {
+    let status = calc.conf(ConfStart);
+    calc.status(&status);
   }
 
   "STO" => fn sto(calc: &mut Calc) {
@@ -380,45 +819,72 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
     if calc.input.is_empty() && calc.x != Num::ZERO {
       calc.x = Num::ZERO;
     } else {
-      let rev_take = calc.input.chars().rev().skip(1).collect::<String>();
-      calc.input = rev_take.chars().rev().collect();
+      calc.del_input();
     }
   }
 
+  "LEFT" => fn left(calc: &mut Calc) {
+    calc.cursor = calc.cursor.saturating_sub(1);
+  }
+
+  "RIGHT" => fn right(calc: &mut Calc) {
+    calc.cursor = calc.input.chars().count().min(calc.cursor + 1);
+  }
+
   "ADD" => fn add(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.y.add_num(calc.x));
+    if calc.check_units("+") {
+      calc.down_with_x(calc.y.add_num(calc.x));
+    }
   }
 
   "SIN" => fn sin(calc: &mut Calc) input_x base {
-    calc.x = calc.x.sin();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.sin();
+    }
   }
 
   "ASIN" => fn asin(calc: &mut Calc) input_x base {
-    calc.x = calc.x.asin();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.asin();
+    }
   }
 
   "COS" => fn cos(calc: &mut Calc) input_x base {
-    calc.x = calc.x.cos();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.cos();
+    }
   }
 
   "ACOS" => fn acos(calc: &mut Calc) input_x base {
-    calc.x = calc.x.acos();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.acos();
+    }
   }
 
   "TAN" => fn tan(calc: &mut Calc) input_x base {
-    calc.x = calc.x.tan();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.tan();
+    }
   }
 
   "ATAN" => fn atan(calc: &mut Calc) input_x base {
-    calc.x = calc.x.atan();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.atan();
+    }
   }
 
   "SUB" => fn sub(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.y.sub_num(calc.x));
+    if calc.check_units("-") {
+      calc.down_with_x(calc.y.sub_num(calc.x));
+    }
   }
 
-  "CHS" => fn chs(calc: &mut Calc) input_x base {
-    calc.x = calc.x.chs();
+  "CHS" => fn chs(calc: &mut Calc) base {
+    if calc.input.is_empty() {
+      calc.x = calc.x.chs();
+    } else {
+      calc.chs_input();
+    }
   }
 
   "ABS" => fn abs(calc: &mut Calc) input_x base {
@@ -426,23 +892,33 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
   }
 
   "LD" => fn ld(calc: &mut Calc) input_x base {
-    calc.x = calc.x.ld();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.ld();
+    }
   }
 
   "LOG" => fn log(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.y.log(calc.x));
+    if calc.check_plain(&[calc.y, calc.x]) {
+      calc.down_with_x(calc.y.log(calc.x));
+    }
   }
 
   "MUL" => fn mul(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.x.mul_num(calc.y));
+    if calc.check_units("*") {
+      calc.down_with_x(calc.y.mul_num(calc.x));
+    }
   }
 
   "LB" => fn lb(calc: &mut Calc) input_x base {
-    calc.x = calc.x.lb();
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.lb();
+    }
   }
 
   "POW" => fn pow(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.y.pow(calc.x));
+    if calc.check_units("^") {
+      calc.down_with_x(calc.y.pow(calc.x));
+    }
   }
 
   "RECIP" => fn recip(calc: &mut Calc) input_x base {
@@ -450,35 +926,130 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
   }
 
   "ROOT" => fn root(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.y.root(calc.x));
+    if calc.check_units("√") {
+      calc.down_with_x(calc.y.root(calc.x));
+    }
+  }
+
+  "SQRT" => fn sqrt(calc: &mut Calc) input_x base {
+    match calc.x.check_units("√", Num::from_z(2)) {
+      Ok(()) => calc.x = calc.x.sqrt(),
+      Err(err) => calc.unit_error(err),
+    }
+  }
+
+  "SQR" => fn sqr(calc: &mut Calc) input_x base {
+    calc.x = calc.x.mul_num(calc.x);
+  }
+
+  "PI" => fn pi(calc: &mut Calc) input_x base {
+    calc.up_with_x(Num::PI);
+  }
+
+  "CATALOG" => fn catalog(calc: &mut Calc) input_x base {
+    calc.state = Catalog(CatalogStart);
+    calc.status_msg("catalog_menu", &[]);
+  }
+
+  "LAST_X" => fn last_x(calc: &mut Calc) input_x base {
+    calc.up_with_x(calc.last_x);
+  }
+
+  "CONVERT" => fn convert(calc: &mut Calc) input_x base {
+    match calc.y.convert(calc.x) {
+      Ok(y) => calc.down_with_x(y),
+      Err(err) => calc.unit_error(err),
+    }
   }
 
   "DIV" => fn div(calc: &mut Calc) input_x base {
-    calc.down_with_x(calc.y.div_num(calc.x));
+    if calc.check_units("/") {
+      calc.down_with_x(calc.y.div_num(calc.x));
+    }
   }
 
   "INT" => fn int(calc: &mut Calc) input_x base {
     calc.x = calc.x.int();
   }
 
+  "FAC" => fn fac(calc: &mut Calc) input_x base {
+    match calc.x.fac() {
+      Some(x) => calc.x = x,
+      None => calc.status_msg("err_fac", &[]),
+    }
+  }
+
+  "MOD" => fn modulo(calc: &mut Calc) input_x base {
+    if calc.check_plain(&[calc.y, calc.x]) {
+      calc.down_with_x(calc.y.modulo(calc.x));
+    }
+  }
+
+  "GCD" => fn gcd(calc: &mut Calc) input_x base {
+    match calc.y.gcd(calc.x) {
+      Some(x) => calc.down_with_x(x),
+      None => calc.status_msg("err_integers", &[]),
+    }
+  }
+
+  "EXACT" => fn exact(calc: &mut Calc) input_x base {
+    if !cfg!(feature = "bigint") {
+      calc.status_msg("err_no_exact", &[]);
+      return;
+    }
+
+    let exact = !calc.config.context.exact;
+    calc.set_context(Context { exact, ..calc.config.context });
+    calc.status_msg(if exact { "exact_on" } else { "exact_off" }, &[]);
+  }
+
+  "DECIMAL" => fn decimal(calc: &mut Calc) input_x base {
+    if !cfg!(feature = "decimal") {
+      calc.status_msg("err_no_decimal", &[]);
+      return;
+    }
+
+    let decimal = !calc.config.context.decimal;
+    calc.set_context(Context { decimal, ..calc.config.context });
+    calc.status_msg(if decimal { "decimal_on" } else { "decimal_off" }, &[]);
+  }
+
+  "PREC" => fn prec(calc: &mut Calc) input_x base {
+    let precision = u8::try_from(calc.x.as_z()).unwrap_or_default();
+    if !calc.x.is_z() || !PRECISIONS.contains(&precision) {
+      calc.status_msg("err_precision", &[]);
+      return;
+    }
+
+    calc.set_context(Context { precision, ..calc.config.context });
+    calc.status_msg("prec_set", &[&precision.to_string()]);
+  }
+
+  "MAX_DENOM" => fn max_denom(calc: &mut Calc) input_x base {
+    let max_denom = calc.x.as_z();
+    if !calc.x.is_z() || !MAX_DENOMS.contains(&max_denom) {
+      calc.status_msg("err_max_denom", &[]);
+      return;
+    }
+
+    calc.set_context(Context { max_denom, ..calc.config.context });
+    calc.status_msg("max_denom_set", &[&max_denom.to_string()]);
+  }
+
   "DOT" => fn dot(calc: &mut Calc) base {
-    if let Some(dot_pos) = calc.input.find('.') {
-      let (input, empty) = calc.input.split_at(dot_pos);
-      if empty[1..].is_empty() {
-        if input.len() > 5 {
-          calc.status("Numerator too large");
-          return;
-        }
-        calc.input = format!("{input}/");
-        return;
+    // 2 . . 5 gives 2/5 and 2 . 3 . 8 the mixed number 2 3/8, but the parts
+    // after i, ± and ∠ are reals on their own
+    let real = !calc.input.contains(['i', '±', '∠']);
+    if let Some((whole, numer)) = calc.input.split_once('.').filter(|_| real) {
+      let unsigned = whole.trim_start_matches('-');
+      let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+      if !digits(unsigned) || !digits(numer) {
+        calc.status_msg("err_mixed", &[]);
+      } else if numer.is_empty() || unsigned.is_empty() {
+        calc.set_input(format!("{whole}{numer}/"));
       } else {
-        calc.status("Integer part not supported");
-        return;
+        calc.set_input(format!("{whole} {numer}/"));
       }
-    }
-
-    if calc.input.find('/').is_some() {
-      calc.status("Already a fraction");
       return;
     }
 
@@ -489,7 +1060,77 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
     calc.x = calc.x.frac();
   }
 
-  "I" => fn i(calc: &mut Calc) base { calc.add_input("i") }
+  "I" => fn i(calc: &mut Calc) base {
+    calc.add_input("i")
+  }
+
+  "PM" => fn pm(calc: &mut Calc) base {
+    calc.add_input("±")
+  }
+
+  "ANGLE" => fn angle(calc: &mut Calc) base {
+    calc.add_input("∠")
+  }
+
+  "DEG" => fn deg(calc: &mut Calc) input_x base {
+    let degrees = !calc.config.context.degrees;
+    calc.set_context(Context { degrees, ..calc.config.context });
+    calc.status_msg(if degrees { "deg_on" } else { "deg_off" }, &[]);
+  }
+
+  "POLAR" => fn polar(calc: &mut Calc) input_x base {
+    let polar = !calc.config.context.polar;
+    calc.set_context(Context { polar, ..calc.config.context });
+    calc.status_msg(if polar { "polar_on" } else { "polar_off" }, &[]);
+  }
+
+  "SHOW" => fn show(calc: &mut Calc) input_x base {
+    calc.show_x(&calc.msg("show_x", &[]));
+  }
+
+  "MIXED" => fn mixed(calc: &mut Calc) input_x base {
+    let mixed = !calc.config.context.mixed;
+    calc.set_context(Context { mixed, ..calc.config.context });
+    calc.status_msg(if mixed { "mixed_on" } else { "mixed_off" }, &[]);
+  }
+
+  "FORMAT" => fn format(calc: &mut Calc) input_x base {
+    let format = calc.config.context.format.next();
+    calc.set_context(Context { format, ..calc.config.context });
+    calc.status_msg("format_set", &[&format.apply("1234567.89")]);
+  }
+
+  "TO_POLAR" => fn to_polar(calc: &mut Calc) input_x base {
+    if calc.check_plain(&[calc.x]) {
+      let (r, theta) = calc.x.to_polar();
+      calc.x = theta;
+      calc.up_with_x(r);
+    }
+  }
+
+  "TO_RECT" => fn to_rect(calc: &mut Calc) input_x base {
+    if calc.check_plain(&[calc.y, calc.x]) {
+      calc.down_with_x(Num::from_polar(calc.x, calc.y));
+    }
+  }
+
+  "ARG" => fn arg(calc: &mut Calc) input_x base {
+    if calc.check_plain(&[calc.x]) {
+      calc.x = calc.x.arg();
+    }
+  }
+
+  "CONJ" => fn conj(calc: &mut Calc) input_x base {
+    calc.x = calc.x.conj();
+  }
+
+  "RE" => fn re(calc: &mut Calc) input_x base {
+    calc.x = calc.x.re();
+  }
+
+  "IM" => fn im(calc: &mut Calc) input_x base {
+    calc.x = calc.x.im();
+  }
 
   "ROUND" => fn round(calc: &mut Calc) input_x base {
     calc.x = calc.x.round();
@@ -503,8 +1144,16 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
 
   "_INPUT_X" => fn input_x(calc: &mut Calc) {
     if !calc.input.is_empty() {
-      calc.x = Num::decode(&calc.input);
+      match entry::decode(&calc.input) {
+        Ok(x) => calc.x = x,
+        // Like never entered, for example a mixed number without denominator
+        Err(_) => {
+          calc.down_with_x(calc.y);
+          calc.status_msg("err_input", &[]);
+        }
+      }
       calc.input.clear();
+      calc.cursor = 0;
     }
   }
 
@@ -518,18 +1167,15 @@ pub static COMMANDS: phf::Map<&str, fn(&mut Calc)> = commands! {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::text::width;
 
   fn log(s: &str) {
     println!("{s}");
   }
-  fn lang(_: &str, _: &str) -> String {
-    "".into()
-  }
-  fn key(_: &str) -> String {
-    "".into()
-  }
 
-  const JS_CALLS: JsCalls = JsCalls { log, lang, key };
+  fn store_config(_: &str) {}
+
+  const JS_CALLS: JsCalls = JsCalls { log, store_config };
 
   #[test]
   fn test_arithmetic_simple() {
@@ -555,6 +1201,607 @@ mod tests {
     calc.y = Num::from_r(-3.0);
     calc.handle_command("DIV");
     assert_eq!(calc.y, Num::from_r(0.0));
-    assert_eq!(calc.x, Num::from_r(-0.84965034430124));
+    assert_eq!(calc.x, Num::from_r(-0.849650344301236));
+  }
+
+  #[test]
+  fn test_translate_key_press() {
+    let mut calc = Calc::new(JS_CALLS);
+    assert_eq!(calc.translate_key_press("A-s".into()), "SIN");
+    assert_eq!(calc.translate_key_press("x".into()), "");
+
+    calc.config.keymap.load("x = XY").unwrap();
+    assert_eq!(calc.translate_key_press("x".into()), "XY");
+  }
+
+  #[test]
+  fn test_help() {
+    let mut calc = Calc::new(JS_CALLS);
+    calc.lang = Lang::De;
+    calc.handle_command("XY_long");
+    assert_eq!(
+      calc.text,
+      "Vertauscht die Werte von x und y.\nDer Rest des Stapels bleibt\n\
+      unverändert."
+    );
+    assert_eq!(calc.scroll, 1);
+  }
+
+  fn press(calc: &mut Calc, index: u8) {
+    let command = calc.translate_button_press(index, false);
+    calc.handle_command(&command);
+  }
+
+  #[test]
+  fn test_conf_button() {
+    let mut calc = Calc::new(JS_CALLS);
+
+    // Put SIN (ALT layer, button 11) on the BASE layer button A (index 0)
+    calc.handle_command("CONF");
+    calc.handle_command("A");
+    press(&mut calc, 0);
+    assert_eq!(calc.state, Conf(ConfCommand(Layer::Base, 0, Layer::Base)));
+    press(&mut calc, MODE_BUTTON);
+    press(&mut calc, 11);
+    assert_eq!(calc.text, "Button set to SIN");
+    assert_eq!(calc.state, Base);
+    assert_eq!(calc.translate_button_press(0, false), "SIN");
+
+    // The mode button stays
+    calc.handle_command("CONF");
+    calc.handle_command("B");
+    press(&mut calc, MODE_BUTTON);
+    assert_eq!(calc.text, "Can't change the mode button");
+    assert_eq!(calc.translate_button_press(MODE_BUTTON, false), "ALT");
+
+    calc.handle_command("CONF");
+    calc.handle_command("E");
+    assert_eq!(calc.config, Config::default());
+  }
+
+  #[test]
+  fn test_conf_key() {
+    let mut calc = Calc::new(JS_CALLS);
+
+    calc.handle_command("CONF");
+    calc.handle_command("D");
+    let command = calc.translate_key_press("s".into());
+    assert_eq!(command, "_KEY_s");
+    calc.handle_command(&command);
+    press(&mut calc, MODE_BUTTON);
+    press(&mut calc, 11);
+    assert_eq!(calc.text, "Key s set to SIN");
+    assert_eq!(calc.translate_key_press("s".into()), "SIN");
+  }
+
+  #[test]
+  fn test_display_width() {
+    let mut calc = Calc::new(JS_CALLS);
+    calc.handle_command("INV");
+    calc.set_input("1".repeat(40));
+    calc.status(&"Status too long ".repeat(3));
+    for line in calc.display().lines() {
+      assert!(width(line) <= WIDTH, "`{line}`");
+    }
+    let x = format!("› …{}_", "1".repeat(31));
+    assert!(calc.display().contains(&x));
+    calc.cursor = 0;
+    let x = format!("› _{}…", "1".repeat(31));
+    assert!(calc.display().contains(&x));
+
+    calc.handle_command("ENTER_long");
+    calc.scroll = 10;
+    for line in calc.display().lines() {
+      assert!(width(line) <= WIDTH, "`{line}`");
+    }
+  }
+
+  #[test]
+  fn test_input_editor() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "2", "3", "LEFT", "LEFT", "5"] {
+      calc.handle_command(command);
+    }
+    assert_eq!((calc.input.as_str(), calc.cursor), ("1523", 2));
+    assert!(calc.display().contains("› 15_23"));
+    for command in ["DEL", "DEL", "DEL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!((calc.input.as_str(), calc.cursor), ("23", 0));
+
+    calc.handle_command("CHS");
+    assert_eq!((calc.input.as_str(), calc.cursor), ("-23", 1));
+    for command in ["RIGHT", "RIGHT", "RIGHT", "E", "4", "CHS"] {
+      calc.handle_command(command);
+    }
+    assert_eq!((calc.input.as_str(), calc.cursor), ("-23e-4", 6));
+    for command in ["I", "5", "CHS", "CHS", "CHS"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.input, "-23e-4i-5");
+    calc.handle_command("ENTER");
+    assert_eq!(calc.x.disp(Std), "-0.0023i-5");
+
+    calc.handle_command("DEL");
+    assert_eq!(calc.x, Num::ZERO);
+  }
+
+  #[test]
+  fn test_entry() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "E", "E"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: Duplicate 'e'");
+    calc.handle_command("DOT");
+    assert_eq!(calc.text, "Error: No '.' here");
+    for command in ["DEL", "I", "DOT", "5", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "1i0.5");
+
+    for command in ["DISP", "D", "A", "2", "A", "E", "CHS", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "-686");
+
+    calc.handle_command("DISP");
+    calc.handle_command("A");
+    for command in ["9", "E", "9", "9", "9", "ANGLE"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: No polar form of ∞");
+    calc.handle_command("ENTER");
+    assert_eq!(calc.x.disp(Std), "+oo");
+
+    for command in ["2", "DOT", "3", "DOT", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: Input not a number");
+    assert_eq!(calc.x.disp(Std), "+oo");
+  }
+
+  #[test]
+  fn test_paste() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "2"] {
+      calc.handle_command(command);
+    }
+    calc.paste("3+4i");
+    assert_eq!(calc.y.disp(Std), "12");
+    assert_eq!(calc.x.disp(Std), "3i4");
+    calc.paste("2∠30°  km");
+    assert_eq!(calc.text, "Error: Not a number at column 8");
+    assert_eq!(calc.x.disp(Std), "3i4");
+  }
+
+  #[test]
+  fn test_entry_fuzz() {
+    // Random keys during entry, the input must always decode
+    let keys = [
+      "0", "1", "5", "A", "F", "DOT", "E", "I", "PM", "ANGLE", "CHS", "DEL",
+      "LEFT", "RIGHT",
+    ];
+    let mut calc = Calc::new(JS_CALLS);
+    let mut random = 0x2545_f491_4f6c_dd1d_u64;
+    for run in 0..2000 {
+      calc.disp = if run % 4 == 0 { HexU } else { Std };
+      for _ in 0..12 {
+        random ^= random << 13;
+        random ^= random >> 7;
+        random ^= random << 17;
+        calc.handle_command(keys[random as usize % keys.len()]);
+        // Except a mixed number without denominator like `1 5/`
+        if let Err(err) = entry::decode(&calc.input) {
+          let denominator = EntryError::Denominator.to_string();
+          let mixed = calc.input.contains(' ') && err.0 == denominator;
+          assert!(mixed, "{}", calc.input);
+        }
+      }
+      calc.handle_command("ENTER");
+    }
+  }
+
+  #[cfg(feature = "bigint")]
+  #[test]
+  fn test_show_big_integer() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["3", "0", "FAC", "DISP", "F"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(
+      calc.text,
+      "Display is: standard\n265'252'859'812'191'058'636'308'\n480'000'000"
+    );
+    assert_eq!(calc.display().lines().last().unwrap().trim(), "480'000'000");
+  }
+
+  #[cfg(feature = "bigint")]
+  #[test]
+  fn test_exact() {
+    let mut calc = Calc::new(JS_CALLS);
+    calc.handle_command("EXACT");
+    assert_eq!(calc.text, "Exact mode on");
+    assert!(calc.config.context.exact);
+
+    for command in ["1", "ENTER", "3", "DIV"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "1/3");
+
+    calc.handle_command("EXACT");
+    assert_eq!(calc.text, "Exact mode off");
+    for command in ["1", "ENTER", "3", "DIV"] {
+      calc.handle_command(command);
+    }
+    assert!(calc.x.is_r());
+  }
+
+  #[cfg(feature = "decimal")]
+  #[test]
+  fn test_decimal() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "DOT", "1", "ENTER", "2", "DOT", "2", "DECIMAL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Decimal mode on");
+    assert!(calc.config.context.decimal);
+    assert_eq!(format!("{:?}", calc.y), "Num(Repr D1.1)");
+
+    calc.handle_command("ADD");
+    assert_eq!(calc.x.disp(Raw), "3.3");
+
+    calc.handle_command("DECIMAL");
+    assert_eq!(calc.text, "Decimal mode off");
+    assert!(calc.x.is_r());
+  }
+
+  #[test]
+  fn test_prec() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["2", "ENTER", "3", "DIV", "1", "2", "PREC"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Precision: 12 digits");
+    assert_eq!(calc.config.context.precision, 12);
+    assert_eq!(format!("{:?}", calc.y), "Num(Repr .666666666667E0)");
+
+    calc.handle_command("9");
+    calc.handle_command("PREC");
+    assert_eq!(calc.text, "Precision 10 to 15 digits only");
+    assert_eq!(calc.config.context.precision, 12);
+  }
+
+  #[test]
+  fn test_symbolic() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["PI", "2", "DIV", "SIN"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x, Num::from_z(1));
+
+    for command in ["2", "SQRT", "SQR"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x, Num::from_z(2));
+
+    for command in ["PI", "3", "MUL", "4", "DIV"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "3π/4");
+    calc.handle_command("SIN");
+    assert_eq!(calc.x.disp(Std), "√2/2");
+    calc.handle_command("ENTER");
+    calc.handle_command("ADD");
+    assert_eq!(calc.x.disp(Std), "√2");
+
+    // Not exact anymore: reals
+    for command in ["PI", "ADD"] {
+      calc.handle_command(command);
+    }
+    assert!(calc.x.is_r());
+    for command in ["PI", "PI", "MUL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "9.86960440109");
+  }
+
+  #[test]
+  fn test_interval() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "2", "DOT", "3", "PM", "0", "DOT", "4", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "12.3 ± 0.4");
+    for command in ["2", "MUL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "24.6 ± 0.8");
+
+    for command in ["1", "0", "PM", "1", "ENTER", "ENTER", "MUL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "100 ± 21");
+    calc.handle_command("SQRT");
+    assert_eq!(calc.x.disp(Fix(3)), "10 ± 1.112");
+    for command in ["2", "POW"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "100 ± 23.4722233074");
+
+    calc.handle_command("PM");
+    assert_eq!(calc.text, "Error: '±' only after a real");
+    for command in ["1", "I", "PM"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: '±' only after a real");
+  }
+
+  #[test]
+  fn test_units() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "0", "0", "UNIT_km/h", "2", "UNIT_h", "MUL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "200 km");
+    for command in ["5", "0", "0", "UNIT_m", "ADD"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "200.5 km");
+
+    for command in ["1", "UNIT_s", "ADD"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: Units km and s don't match");
+    calc.handle_command("UNIT_furlong");
+    assert_eq!(calc.text, "Error: Unknown unit furlong");
+
+    for command in ["UNIT_mi", "CONVERT"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: Units km and s don't match");
+    for command in ["DEL", "UNIT_mi", "CONVERT"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "124.584924044 mi");
+
+    assert!(is_command("UNIT_km/h"));
+    assert!(!is_command("UNIT_furlong"));
+    assert!(!is_command("UNIT_m^-128"));
+
+    calc.handle_command("UNIT_L^100");
+    assert_eq!(calc.text, "Error: Unit exponent beyond ±9");
+    for command in ["1", "UNIT_m^5", "ENTER", "MUL"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: Unit exponent beyond ±9");
+  }
+
+  #[test]
+  fn test_unit_functions() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["4", "UNIT_m²", "SQRT"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "2 m");
+    for command in ["3", "POW"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "8 m³");
+    for command in ["3", "ROOT"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "2 m");
+
+    // Units which can't be carried through are errors, x stays
+    calc.handle_command("SQRT");
+    assert_eq!(calc.text, "Error: No such power of m");
+    for command in ["2", "ENTER", "3", "UNIT_s", "POW"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: No unit allowed, not s");
+    assert_eq!(calc.x.disp(Std), "3 s");
+    calc.handle_command("SIN");
+    assert_eq!(calc.text, "Error: No unit allowed, not s");
+    calc.handle_command("LOG");
+    assert_eq!(calc.text, "Error: No unit allowed, not s");
+    assert_eq!(calc.x.disp(Std), "3 s");
+  }
+
+  #[test]
+  fn test_show() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["2", "SQRT", "3", "I", "7", "ENTER", "DIV"] {
+      calc.handle_command(command);
+    }
+    calc.handle_command("SHOW");
+    assert_eq!(
+      calc.text,
+      "x in full precision:\n0.0731489773641256i\n-0.17068094718296"
+    );
+    assert_eq!(calc.scroll, 2);
+    let display = calc.display();
+    assert_eq!(
+      display.lines().nth(3),
+      Some("0.0731489773641256i                ")
+    );
+
+    calc.handle_command("2");
+    assert_eq!(calc.scroll, 1);
+    calc.handle_command("0");
+    assert_eq!(calc.scroll, 2);
+    calc.handle_command("CHS");
+    assert_eq!(calc.text, "");
+    assert_eq!(calc.x.disp(Std), "-0.0731489773641i0.170680947183");
+  }
+
+  #[test]
+  fn test_mixed() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["2", "DOT", "3", "DOT", "8"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.input, "2 3/8");
+    calc.handle_command("DOT");
+    assert_eq!(calc.text, "Already a fraction");
+    calc.handle_command("ENTER");
+    assert_eq!(calc.x.disp(Std), "19/8");
+    calc.handle_command("MIXED");
+    assert_eq!(calc.text, "Mixed numbers on");
+    assert_eq!(calc.x.disp(Std), "2 3/8");
+    calc.handle_command("CHS");
+    assert_eq!(calc.x.disp(Std), "-2 3/8");
+    assert_eq!("-2 3/8".parse::<Num>().unwrap(), calc.x);
+
+    for command in ["1", "DOT", "5", "E", "2", "DOT"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Not a mixed number");
+    for command in ["ENTER", "DOT", "5", "DOT", "8", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "5/8");
+  }
+
+  #[test]
+  fn test_format() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "2", "3", "4", "DOT", "5", "ENTER"] {
+      calc.handle_command(command);
+    }
+    calc.handle_command("FORMAT");
+    assert_eq!(calc.text, "Number format 1'234'567.89");
+    assert_eq!(calc.x.disp(Std), "1'234.5");
+    assert!(!calc.x.disp(Raw).contains('\''));
+    for _ in 0..3 {
+      calc.handle_command("FORMAT");
+    }
+    assert_eq!(calc.x.disp(Std), "1.234,5");
+    calc.handle_command("FORMAT");
+    assert_eq!(calc.x.disp(Std), "1234.5");
+  }
+
+  #[test]
+  fn test_polar() {
+    let mut calc = Calc::new(JS_CALLS);
+    calc.handle_command("DEG");
+    assert_eq!(calc.text, "Angles in degrees");
+    for command in ["2", "ANGLE", "9", "0", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "0i2");
+    calc.handle_command("POLAR");
+    assert_eq!(calc.text, "Polar display on");
+    assert_eq!(calc.x.disp(Std), "2∠90°");
+
+    calc.handle_command("TO_POLAR");
+    assert_eq!((calc.x.disp(Std), calc.y.disp(Std)), ("2".into(), "90".into()));
+    calc.handle_command("TO_RECT");
+    assert_eq!(calc.x.disp(Std), "2∠90°");
+    calc.handle_command("IM");
+    assert_eq!(calc.x.disp(Std), "2");
+
+    for command in ["3", "0", "SIN"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(Std), "1/2");
+    for command in ["1", "ANGLE", "ANGLE"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Error: '∠' only after a real");
+    assert_eq!(calc.input, "1∠");
+
+    // Each calculator displays in its own context
+    let mut rect = Calc::new(JS_CALLS);
+    for command in ["1", "I", "1", "ENTER"] {
+      rect.handle_command(command);
+    }
+    calc.handle_command("ENTER");
+    assert!(rect.display().contains("1i1"));
+  }
+
+  #[test]
+  fn test_catalog() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["1", "0", "0", "CATALOG", "C"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.state, Catalog(CatalogCategory(2)));
+    assert!(calc.text.starts_with(" °C    °F"));
+    calc.handle_command("A");
+    assert_eq!(calc.x.disp(Std), "212");
+    assert_eq!(calc.text, "Converted °C to °F");
+    assert_eq!(calc.state, Base);
+
+    for command in ["CATALOG", "A", "B", "LAST_X"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.y.disp(Std), "341.180928");
+    assert_eq!(calc.x.disp(Std), "212");
+
+    for command in ["CATALOG", "A", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Bad key? x unchanged");
+    assert_eq!(calc.state, Base);
+  }
+
+  #[test]
+  fn test_conf_lang() {
+    let mut calc = Calc::new(JS_CALLS);
+
+    calc.handle_command("DISP");
+    calc.handle_command("B");
+    calc.handle_command("4");
+    assert_eq!(calc.text, "Display set to: fixed, 4 decimals");
+
+    calc.handle_command("CONF");
+    calc.handle_command("F");
+    assert_eq!(calc.text, "Sprache: Deutsch");
+    assert_eq!(calc.config.lang, Some(Lang::De));
+
+    calc.handle_command("DISP");
+    calc.handle_command("F");
+    assert_eq!(calc.text, "Anzeige ist: fest, 4 Nachkommastellen\n0");
+
+    for command in ["DISP", "C", "E"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.state, Disp(DispEngSi));
+    calc.handle_command("2");
+    assert_eq!(calc.text, "Anzeige: SI-Präfixe, 3 Stellen");
+    assert_eq!(calc.disp, EngSi(2));
+
+    let mut calc = Calc::new(JS_CALLS);
+    assert_eq!(calc.load_config("[settings]\nlang = \"fr\""), Ok(()));
+    assert_eq!(calc.lang, Lang::Fr);
+  }
+
+  #[test]
+  fn test_disp_frac() {
+    let mut calc = Calc::new(JS_CALLS);
+    for command in ["DISP", "B"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Digit for Fix, or: Frac        Show");
+    calc.handle_command("D");
+    assert_eq!(calc.text, "Display set to: fractions, denominator ≤ 999999");
+    for command in ["0", "DOT", "3", "ENTER"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(calc.disp), "3/10");
+
+    for command in ["2", "SQRT"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.x.disp(calc.disp), "≈665857/470832");
+    for command in ["6", "4", "MAX_DENOM"] {
+      calc.handle_command(command);
+    }
+    assert_eq!(calc.text, "Largest denominator: 64");
+    assert_eq!(calc.y.disp(calc.disp), "≈41/29");
+    calc.handle_command("1");
+    calc.handle_command("MAX_DENOM");
+    assert_eq!(calc.text, "Denominator 2 to 999999 only");
   }
 }
@@ -0,0 +1,153 @@
+use crate::context;
+use crate::native::types::*;
+use crate::Num;
+
+/// A category of the conversion catalogue with three pairs of units. Each
+/// pair converts both ways, the six conversions are on the buttons A to F.
+pub struct Category {
+  pub pairs: [Pair; 3],
+}
+
+/// The conversion `to = from · factor + offset` with the factor and the offset
+/// as fractions. The offset is for the affine temperature scales.
+pub struct Pair {
+  pub from: &'static str,
+  pub to: &'static str,
+  factor: (Z, Z),
+  offset: (Z, Z),
+}
+
+const fn pair(from: &'static str, to: &'static str, factor: (Z, Z)) -> Pair {
+  Pair { from, to, factor, offset: (0, 1) }
+}
+
+const fn affine(
+  from: &'static str,
+  to: &'static str,
+  factor: (Z, Z),
+  offset: (Z, Z),
+) -> Pair {
+  Pair { from, to, factor, offset }
+}
+
+/// Length, mass, temperature, pressure, energy and data size in the
+/// order of the menu `catalog_menu`
+#[rustfmt::skip]
+pub static CATEGORIES: [Category; 6] = [
+  Category { pairs: [
+    pair("km", "mi", (1_000_000, 1_609_344)),
+    pair("m", "ft", (10_000, 3_048)),
+    pair("in", "cm", (254, 100)),
+  ] },
+  Category { pairs: [
+    pair("kg", "lb", (100_000_000, 45_359_237)),
+    pair("g", "oz", (1_000_000_000, 28_349_523_125)),
+    pair("st", "kg", (635_029_318, 100_000_000)),
+  ] },
+  Category { pairs: [
+    affine("°C", "°F", (9, 5), (32, 1)),
+    affine("°C", "K", (1, 1), (27_315, 100)),
+    affine("°F", "K", (5, 9), (229_835, 900)),
+  ] },
+  Category { pairs: [
+    pair("bar", "psi", (645_160_000_000_000, 44_482_216_152_605)),
+    pair("atm", "kPa", (101_325, 1_000)),
+    pair("kPa", "mmHg", (1_000_000_000_000, 133_322_387_415)),
+  ] },
+  Category { pairs: [
+    pair("kWh", "MJ", (36, 10)),
+    pair("kcal", "kJ", (4_184, 1_000)),
+    pair("BTU", "kJ", (105_505_585_262, 100_000_000_000)),
+  ] },
+  Category { pairs: [
+    pair("MB", "MiB", (15_625, 16_384)),
+    pair("GB", "GiB", (1_953_125, 2_097_152)),
+    pair("TB", "TiB", (244_140_625, 268_435_456)),
+  ] },
+];
+
+impl Category {
+  /// The units of the conversion on button 0 to 5, the even buttons convert
+  /// a pair forward and the odd buttons backward
+  pub fn units(&self, index: usize) -> (&'static str, &'static str) {
+    let pair = &self.pairs[index / 2];
+    if index.is_multiple_of(2) {
+      (pair.from, pair.to)
+    } else {
+      (pair.to, pair.from)
+    }
+  }
+
+  /// Convert x with the conversion on button 0 to 5, see `units()`
+  pub fn convert(&self, index: usize, x: Num) -> Num {
+    let pair = &self.pairs[index / 2];
+    let (factor, offset) = (num(pair.factor), num(pair.offset));
+    if index.is_multiple_of(2) {
+      x.mul_num(factor).add_num(offset)
+    } else {
+      x.sub_num(offset).div_num(factor)
+    }
+  }
+
+  /// The menu on two lines, the units to convert from above the units to
+  /// convert to, in the columns of the buttons A to F
+  pub fn menu(&self) -> String {
+    let line = |unit: fn((&str, &str)) -> String| {
+      let units = (0..6).map(|i| format!("{:6}", unit(self.units(i))));
+      format!(" {}", units.collect::<String>().trim_end())
+    };
+
+    let from = line(|(from, _)| from.into());
+    let to = line(|(_, to)| format!("→{to}"));
+    format!("{from}\n{to}")
+  }
+}
+
+// Exact quotients only in the exact mode, else 1 ft isn't 0.3048 m but 381/1250
+fn num((numer, denom): (Z, Z)) -> Num {
+  if context::is_exact() {
+    Num::from_q(numer, denom)
+  } else {
+    Num::from_r(numer as R / denom as R)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::text::{width, WIDTH};
+
+  fn convert(category: usize, index: usize, x: &str) -> String {
+    let x = x.parse().unwrap();
+    CATEGORIES[category].convert(index, x).disp(crate::NumDisplay::Std)
+  }
+
+  #[test]
+  fn test_convert() {
+    assert_eq!(convert(0, 3, "1"), "0.3048");
+    assert_eq!(convert(0, 4, "1"), "2.54");
+    assert_eq!(convert(1, 1, "1"), "0.45359237");
+    assert_eq!(convert(2, 0, "100"), "212");
+    assert_eq!(convert(2, 1, "-40"), "-40");
+    assert_eq!(convert(2, 3, "0"), "-273.15");
+    assert_eq!(convert(2, 4, "32"), "273.15");
+    assert_eq!(convert(3, 2, "1"), "101.325");
+    assert_eq!(convert(4, 0, "1"), "3.6");
+    assert_eq!(convert(5, 1, "15625"), "16384");
+  }
+
+  #[test]
+  fn test_menu() {
+    assert_eq!(
+      CATEGORIES[2].menu(),
+      " °C    °F    °C    K     °F    K\n →°F   →°C   →K    →°C   →K    →°F"
+    );
+    for category in &CATEGORIES {
+      for line in category.menu().lines() {
+        assert!(width(line) <= WIDTH, "{line}");
+      }
+    }
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,428 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::calc::{is_command, ALT_BUTTONS, BASE_BUTTONS, INV_BUTTONS};
+use crate::context::{MAX_DENOMS, PRECISIONS};
+use crate::keymap::{parse_line, Keymap, DEFAULT_KEYS};
+use crate::{Context, Lang};
+
+/// Count of buttons on each layer
+pub const BUTTONS_N: usize = 26;
+
+/// The button switching between the layers, it can't be changed
+pub const MODE_BUTTON: u8 = 25;
+
+/// The three button layers, see `State::Base`, `State::Alt` and `State::Inv`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)] #[rustfmt::skip]
+pub enum Layer { Base, Alt, Inv }
+
+impl Layer {
+  pub const ALL: [Layer; 3] = [Layer::Base, Layer::Alt, Layer::Inv];
+
+  pub fn label(self) -> &'static str {
+    match self {
+      Layer::Base => "BASE",
+      Layer::Alt => "ALT",
+      Layer::Inv => "INV",
+    }
+  }
+
+  /// Section name in the configuration text
+  pub fn section(self) -> &'static str {
+    match self {
+      Layer::Base => "base",
+      Layer::Alt => "alt",
+      Layer::Inv => "inv",
+    }
+  }
+
+  pub fn next(self) -> Self {
+    match self {
+      Layer::Base => Layer::Alt,
+      Layer::Alt => Layer::Inv,
+      Layer::Inv => Layer::Base,
+    }
+  }
+
+  pub fn default_buttons(self) -> &'static [&'static str] {
+    match self {
+      Layer::Base => BASE_BUTTONS,
+      Layer::Alt => ALT_BUTTONS,
+      Layer::Inv => INV_BUTTONS,
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigError(pub String);
+
+impl fmt::Display for ConfigError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl Error for ConfigError {}
+
+/// The user configuration: the commands on the buttons of each layer, the
+/// keyboard keys, the language if chosen by the user and the context of the
+/// calculations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+  pub buttons: [Vec<String>; 3],
+  pub keymap: Keymap,
+  pub lang: Option<Lang>,
+  pub context: Context,
+}
+
+// The section of the configuration text being loaded
+#[derive(Copy, Clone, Debug, PartialEq)] #[rustfmt::skip]
+enum Section { Keys, Settings, Buttons(Layer) }
+
+impl Default for Config {
+  fn default() -> Self {
+    let buttons = |layer: Layer| {
+      layer.default_buttons().iter().map(|&c| c.into()).collect()
+    };
+
+    Config {
+      buttons: Layer::ALL.map(buttons),
+      keymap: Keymap::default(),
+      lang: None,
+      context: Context::default(),
+    }
+  }
+}
+
+impl Config {
+  pub fn button(&self, layer: Layer, index: u8) -> &str {
+    let buttons = &self.buttons[layer as usize];
+    buttons.get(index as usize).map(String::as_str).unwrap_or_default()
+  }
+
+  pub fn set_button(
+    &mut self,
+    layer: Layer,
+    index: u8,
+    command: &str,
+  ) -> Result<(), ConfigError> {
+    if index as usize >= BUTTONS_N {
+      let msg = format!("button {index} beyond {}", BUTTONS_N - 1);
+      Err(ConfigError(msg))
+    } else if index == MODE_BUTTON {
+      Err(ConfigError("the mode button can't be changed".into()))
+    } else if !is_command(command) {
+      Err(ConfigError(format!("unknown command `{command}`")))
+    } else {
+      self.buttons[layer as usize][index as usize] = command.into();
+      Ok(())
+    }
+  }
+
+  /// Load the configuration as written by `to_text()`. The sections are
+  /// `[keys]` with `key = COMMAND` lines like `Keymap::load()`, `[settings]`
+  /// with `lang = "de"`, `exact = true`, `decimal = true`, `precision = 12`,
+  /// `degrees = true`, `polar = true`, `mixed = true`, `max_denom = 64`,
+  /// `group = "'"` (empty for no groups), `group_size = 3` and
+  /// `decimal_mark = ","` and `[base]`, `[alt]` and `[inv]` with
+  /// `index = COMMAND` lines, index 0 is the top left button A. Lines before
+  /// the first section are keys. On error the configuration is left
+  /// unchanged.
+  pub fn load(&mut self, text: &str) -> Result<(), ConfigError> {
+    let mut config = self.clone();
+    let mut section = Section::Keys;
+
+    for (n, line) in text.lines().enumerate() {
+      let err = |msg: &str| ConfigError(format!("line {}: {msg}", n + 1));
+
+      if let Some(name) = line.trim().strip_prefix('[') {
+        let name = name.strip_suffix(']').ok_or(err("missing `]`"))?;
+        section = match name {
+          "keys" => Section::Keys,
+          "settings" => Section::Settings,
+          _ => match Layer::ALL.into_iter().find(|l| l.section() == name) {
+            Some(layer) => Section::Buttons(layer),
+            None => return Err(err(&format!("unknown section `{name}`"))),
+          },
+        };
+        continue;
+      }
+
+      let Some((key, command)) = parse_line(line).map_err(err)? else {
+        continue;
+      };
+
+      match section {
+        Section::Keys => {
+          config.keymap.bind(&key, &command).map_err(|e| err(&e.0))?
+        }
+        Section::Settings => match key.as_str() {
+          "lang" => match Lang::from_code(&command) {
+            Some(lang) => config.lang = Some(lang),
+            None => return Err(err(&format!("unknown lang `{command}`"))),
+          },
+          "exact" | "decimal" | "degrees" | "polar" | "mixed" => {
+            let on = command.parse();
+            let on = on.map_err(|_| err("expected `true` or `false`"))?;
+            match key.as_str() {
+              "exact" => config.context.exact = on,
+              "decimal" => config.context.decimal = on,
+              "degrees" => config.context.degrees = on,
+              "polar" => config.context.polar = on,
+              _ => config.context.mixed = on,
+            }
+          }
+          "precision" => match command.parse() {
+            Ok(precision) if PRECISIONS.contains(&precision) => {
+              config.context.precision = precision
+            }
+            _ => return Err(err("expected precision 10 to 15")),
+          },
+          "max_denom" => match command.parse() {
+            Ok(max_denom) if MAX_DENOMS.contains(&max_denom) => {
+              config.context.max_denom = max_denom
+            }
+            _ => return Err(err("expected max_denom 2 to 999999")),
+          },
+          "group" | "decimal_mark" => {
+            let mut chars = command.chars();
+            let c = match (chars.next(), chars.next()) {
+              (c, None) if !c.is_some_and(|c| c.is_ascii_digit()) => c,
+              _ => return Err(err("expected one character, not a digit")),
+            };
+            match (key.as_str(), c) {
+              ("group", c) => config.context.format.group = c,
+              (_, Some(c)) => config.context.format.mark = c,
+              (_, None) => return Err(err("expected a decimal mark")),
+            }
+          }
+          "group_size" => match command.parse() {
+            Ok(size @ 1..=9) => config.context.format.group_size = size,
+            _ => return Err(err("expected group size 1 to 9")),
+          },
+          _ => return Err(err(&format!("unknown setting `{key}`"))),
+        },
+        Section::Buttons(layer) => {
+          let index = key.parse().map_err(|_| err("expected button index"))?;
+          config.set_button(layer, index, &command).map_err(|e| err(&e.0))?
+        }
+      }
+    }
+    *self = config;
+
+    Ok(())
+  }
+
+  /// The configuration as text for `load()`, only the changes to the
+  /// default configuration.
+  pub fn to_text(&self) -> String {
+    let mut text = String::new();
+
+    let default_keymap = Keymap::default();
+    let mut keys = self
+      .keymap
+      .iter()
+      .filter(|&(key, command)| default_keymap.get(key) != Some(command))
+      .collect::<Vec<_>>();
+    let removed =
+      DEFAULT_KEYS.iter().filter(|(k, _)| self.keymap.get(k).is_none());
+    keys.extend(removed.map(|&(key, _)| (key, "")));
+    keys.sort();
+    if !keys.is_empty() {
+      text.push_str("[keys]\n");
+      for (key, command) in keys {
+        text.push_str(&format!("{} = {}\n", quote(key), quote(command)));
+      }
+    }
+
+    let mut settings = Vec::new();
+    if let Some(lang) = self.lang {
+      settings.push(format!("lang = {}\n", quote(lang.code())));
+    }
+    let default = Context::default();
+    if self.context.exact != default.exact {
+      settings.push(format!("exact = {}\n", self.context.exact));
+    }
+    if self.context.decimal != default.decimal {
+      settings.push(format!("decimal = {}\n", self.context.decimal));
+    }
+    if self.context.precision != default.precision {
+      settings.push(format!("precision = {}\n", self.context.precision));
+    }
+    if self.context.degrees != default.degrees {
+      settings.push(format!("degrees = {}\n", self.context.degrees));
+    }
+    if self.context.polar != default.polar {
+      settings.push(format!("polar = {}\n", self.context.polar));
+    }
+    if self.context.mixed != default.mixed {
+      settings.push(format!("mixed = {}\n", self.context.mixed));
+    }
+    if self.context.max_denom != default.max_denom {
+      settings.push(format!("max_denom = {}\n", self.context.max_denom));
+    }
+    let (format, default) = (self.context.format, default.format);
+    if format.group != default.group {
+      let group = format.group.map(String::from).unwrap_or_default();
+      settings.push(format!("group = {}\n", quote(&group)));
+    }
+    if format.group_size != default.group_size {
+      settings.push(format!("group_size = {}\n", format.group_size));
+    }
+    if format.mark != default.mark {
+      settings
+        .push(format!("decimal_mark = {}\n", quote(&format.mark.to_string())));
+    }
+    if !settings.is_empty() {
+      if !text.is_empty() {
+        text.push('\n');
+      }
+      text.push_str("[settings]\n");
+      text.extend(settings);
+    }
+
+    for layer in Layer::ALL {
+      let changed = self.buttons[layer as usize]
+        .iter()
+        .zip(layer.default_buttons())
+        .enumerate()
+        .filter(|(_, (command, default))| command != default)
+        .collect::<Vec<_>>();
+      if changed.is_empty() {
+        continue;
+      }
+
+      if !text.is_empty() {
+        text.push('\n');
+      }
+      text.push_str(&format!("[{}]\n", layer.section()));
+      for (index, (command, _)) in changed {
+        text.push_str(&format!("{index} = {}\n", quote(command)));
+      }
+    }
+
+    text
+  }
+}
+
+// Quote like TOML, with single quotes if there's a double quote
+fn quote(s: &str) -> String {
+  if s.contains('"') {
+    format!("'{s}'")
+  } else {
+    format!("\"{s}\"")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_set_button() {
+    let mut config = Config::default();
+    assert_eq!(config.button(Layer::Alt, 11), "SIN");
+
+    assert_eq!(config.set_button(Layer::Base, 0, "SIN"), Ok(()));
+    assert_eq!(config.button(Layer::Base, 0), "SIN");
+
+    let err = |s: &str| Err(ConfigError(s.into()));
+    assert_eq!(
+      config.set_button(Layer::Base, 26, "SIN"),
+      err("button 26 beyond 25")
+    );
+    assert_eq!(
+      config.set_button(Layer::Inv, MODE_BUTTON, "SIN"),
+      err("the mode button can't be changed")
+    );
+    assert_eq!(
+      config.set_button(Layer::Base, 1, "NOPE"),
+      err("unknown command `NOPE`")
+    );
+  }
+
+  #[test]
+  fn test_text_round_trip() {
+    assert_eq!(Config::default().to_text(), "");
+
+    let mut config = Config::default();
+    config.set_button(Layer::Base, 0, "SIN").unwrap();
+    config.set_button(Layer::Inv, 24, "ROUND").unwrap();
+    config.keymap.bind("\"", "ENTER").unwrap();
+    config.keymap.bind("!", "").unwrap();
+    config.lang = Some(Lang::Fr);
+    config.context.exact = true;
+    config.context.decimal = true;
+    config.context.precision = 12;
+    config.context.degrees = true;
+    config.context.polar = true;
+    config.context.mixed = true;
+    config.context.max_denom = 64;
+    config.context.format.group = Some(' ');
+    config.context.format.mark = ',';
+
+    let text = config.to_text();
+    assert_eq!(
+      text,
+      "[keys]\n\"!\" = \"\"\n'\"' = \"ENTER\"\n\n\
+        [settings]\nlang = \"fr\"\nexact = true\ndecimal = true\n\
+        precision = 12\ndegrees = true\npolar = true\nmixed = true\n\
+        max_denom = 64\ngroup = \" \"\n\
+        decimal_mark = \",\"\n\n\
+        [base]\n0 = \"SIN\"\n\n[inv]\n24 = \"ROUND\"\n"
+    );
+
+    let mut loaded = Config::default();
+    assert_eq!(loaded.load(&text), Ok(()));
+    assert_eq!(loaded, config);
+  }
+
+  #[test]
+  fn test_load_errors() {
+    let mut config = Config::default();
+    let err = |s: &str| Err(ConfigError(s.into()));
+
+    assert_eq!(config.load("[base\n"), err("line 1: missing `]`"));
+    assert_eq!(config.load("[foo]"), err("line 1: unknown section `foo`"));
+    assert_eq!(
+      config.load("[alt]\nA = SIN"),
+      err("line 2: expected button index")
+    );
+    assert_eq!(
+      config.load("[alt]\n25 = SIN"),
+      err("line 2: the mode button can't be changed")
+    );
+    assert_eq!(config.load("x = NOPE"), err("line 1: unknown command `NOPE`"));
+    assert_eq!(
+      config.load("[settings]\nlang = \"it\""),
+      err("line 2: unknown lang `it`")
+    );
+    assert_eq!(
+      config.load("[settings]\nfoo = \"x\""),
+      err("line 2: unknown setting `foo`")
+    );
+    assert_eq!(
+      config.load("[settings]\nexact = yes"),
+      err("line 2: expected `true` or `false`")
+    );
+    assert_eq!(
+      config.load("[settings]\nprecision = 16"),
+      err("line 2: expected precision 10 to 15")
+    );
+    assert_eq!(
+      config.load("[settings]\ngroup = \"12\""),
+      err("line 2: expected one character, not a digit")
+    );
+    assert_eq!(
+      config.load("[settings]\ndecimal_mark = \"\""),
+      err("line 2: expected a decimal mark")
+    );
+    assert_eq!(
+      config.load("[settings]\ngroup_size = 0"),
+      err("line 2: expected group size 1 to 9")
+    );
+    assert_eq!(config, Config::default());
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,144 @@
+use std::cell::Cell;
+
+use crate::disp::NumFormat;
+use crate::native::types::Z;
+
+/// The settings for the calculations which don't belong to a single number.
+/// The numbers read the context of their thread, installed by a
+/// `ContextGuard` only. `Calc` owns one for its context.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Context {
+  /// Exact quotients beyond the 999999 limit and integer division giving
+  /// quotients, only with the feature `bigint`
+  pub exact: bool,
+
+  /// Decimal reals instead of binary floating point numbers, only with the
+  /// feature `decimal`
+  pub decimal: bool,
+
+  /// Significant digits of the reals, see `PRECISIONS`
+  pub precision: u8,
+
+  /// Angles in degrees instead of radians: the trigonometric functions and
+  /// the polar form of complex numbers
+  pub degrees: bool,
+
+  /// Display complex numbers in the polar form `r∠θ`
+  pub polar: bool,
+
+  /// Display quotients beyond ±1 as mixed numbers like `2 3/8`
+  pub mixed: bool,
+
+  /// The largest denominator of the quotients approximating reals, see
+  /// `NumDisplay::Frac` and `Native::as_q()`
+  pub max_denom: Z,
+
+  /// Digit groups and decimal mark of the displayed numbers
+  pub format: NumFormat,
+}
+
+/// The precisions of the reals: 10 to 15 digits, because the binary floating
+/// point numbers have 15.95 decimal digits
+pub const PRECISIONS: std::ops::RangeInclusive<u8> = 10..=15;
+
+/// The largest denominators: quotients are limited to 999999
+pub const MAX_DENOMS: std::ops::RangeInclusive<Z> = 2..=999_999;
+
+impl Default for Context {
+  fn default() -> Self {
+    Context {
+      exact: false,
+      decimal: false,
+      precision: *PRECISIONS.end(),
+      degrees: false,
+      polar: false,
+      mixed: false,
+      max_denom: *MAX_DENOMS.end(),
+      format: NumFormat::default(),
+    }
+  }
+}
+
+thread_local! {
+  static CONTEXT: Cell<Context> = Cell::new(Context::default());
+}
+
+/// The context of the current thread
+pub fn get() -> Context {
+  CONTEXT.with(Cell::get)
+}
+
+/// Installs a context for the current thread while it lives and restores the
+/// context installed before when dropped
+#[must_use = "the context is restored when the guard is dropped"]
+pub struct ContextGuard {
+  previous: Context,
+}
+
+impl ContextGuard {
+  pub fn new(context: Context) -> Self {
+    ContextGuard { previous: CONTEXT.with(|cell| cell.replace(context)) }
+  }
+
+  /// Install another context, or the same again after other guards
+  pub fn install(&self, context: Context) {
+    CONTEXT.with(|cell| cell.set(context))
+  }
+}
+
+impl Drop for ContextGuard {
+  fn drop(&mut self) {
+    CONTEXT.with(|cell| cell.set(self.previous))
+  }
+}
+
+// A clone installs the current context again
+impl Clone for ContextGuard {
+  fn clone(&self) -> Self {
+    ContextGuard::new(get())
+  }
+}
+
+// The guard is no state of its owner
+impl PartialEq for ContextGuard {
+  fn eq(&self, _: &Self) -> bool {
+    true
+  }
+}
+
+/// Is the exact mode on? Never without the feature `bigint`.
+pub fn is_exact() -> bool {
+  cfg!(feature = "bigint") && get().exact
+}
+
+/// Significant digits of the reals
+pub fn precision() -> u8 {
+  get().precision
+}
+
+/// Is the decimal mode on? Never without the feature `decimal`.
+pub fn is_decimal() -> bool {
+  cfg!(feature = "decimal") && get().decimal
+}
+
+/// Are angles in degrees?
+pub fn is_degrees() -> bool {
+  get().degrees
+}
+
+/// Are complex numbers displayed in the polar form?
+pub fn is_polar() -> bool {
+  get().polar
+}
+
+/// The largest denominator of approximated quotients
+pub fn max_denom() -> Z {
+  get().max_denom
+}
+
+/// Are quotients displayed as mixed numbers?
+pub fn is_mixed() -> bool {
+  get().mixed
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -1,6 +1,9 @@
 use std::num::ParseIntError;
 
+use crate::context::{self, is_degrees, is_mixed, is_polar, precision};
 use crate::native::types::*;
+use crate::native::Native;
+use crate::text::NUM_WIDTH;
 
 const ENABLE_DEBUG: bool = true;
 // const ENABLE_DEBUG: bool = false;
@@ -12,40 +15,256 @@ macro_rules! d {
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)] #[rustfmt::skip]
-pub enum NumDisplay { #[default] Std, Raw, Fix(u8), Sci(u8), HexU, HexL }
+pub enum NumDisplay {
+  #[default] Std, Raw, Fix(u8), Sci(u8), Eng(u8), EngSi(u8), Frac, HexU, HexL,
+  Full,
+}
 use NumDisplay::*;
 
+/// How numbers are shown except in Raw and hex: the separator of the digit
+/// groups if any, the size of the groups and the decimal mark. The digits after
+/// the decimal mark and in the exponent aren't grouped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumFormat {
+  pub group: Option<char>,
+  pub group_size: u8,
+  pub mark: char,
+}
+
+impl Default for NumFormat {
+  fn default() -> Self {
+    NumFormat { group: None, group_size: 3, mark: '.' }
+  }
+}
+
+impl NumFormat {
+  /// The formats switched through by the command FORMAT
+  #[rustfmt::skip]
+  pub const PRESETS: [NumFormat; 5] = [
+    NumFormat { group: None, group_size: 3, mark: '.' },
+    NumFormat { group: Some('\''), group_size: 3, mark: '.' },
+    NumFormat { group: Some(','), group_size: 3, mark: '.' },
+    NumFormat { group: Some(' '), group_size: 3, mark: ',' },
+    NumFormat { group: Some('.'), group_size: 3, mark: ',' },
+  ];
+
+  /// The next of the presets, the first after a format not in the presets
+  pub fn next(self) -> Self {
+    let i = Self::PRESETS.iter().position(|&format| format == self);
+    Self::PRESETS[i.map_or(0, |i| (i + 1) % Self::PRESETS.len())]
+  }
+
+  /// Group the digits before the decimal point and replace the point by the
+  /// decimal mark in the number display `s`
+  pub fn apply(self, s: &str) -> String {
+    if self == NumFormat::default() {
+      return s.into();
+    }
+
+    let mut formatted = String::new();
+    let mut digits = String::new();
+    let mut group = self.group;
+    for c in s.chars().chain(['\0']) {
+      if c.is_ascii_digit() {
+        digits.push(c);
+        continue;
+      }
+
+      match group {
+        Some(group) => {
+          let size = self.group_size as usize;
+          formatted.push_str(&group_digits(&digits, group, size))
+        }
+        None => formatted.push_str(&digits),
+      }
+      digits.clear();
+
+      // No groups after the point and in the exponent, signs don't matter
+      group = match c {
+        '.' | 'e' | 'E' => None,
+        '-' | '+' => group,
+        _ => self.group,
+      };
+      match c {
+        '.' => formatted.push(self.mark),
+        '\0' => (),
+        c => formatted.push(c),
+      }
+    }
+
+    formatted
+  }
+}
+
+/// Integers in full, in Eng and EngSi from 1000 in engineering notation
 pub fn disp_z(z: Z, disp: NumDisplay) -> String {
   match disp {
-    Std | Fix(..) | Sci(..) | Raw => format!("{z}"),
+    Eng(prec) if z.unsigned_abs() >= 1000 => disp_eng(z as R, prec, false),
+    EngSi(prec) if z.unsigned_abs() >= 1000 => disp_eng(z as R, prec, true),
+    Std | Fix(..) | Sci(..) | Eng(..) | EngSi(..) | Frac | Full | Raw => {
+      format!("{z}")
+    }
     HexU => format!("{z:X}"),
     HexL => format!("{z:x}"),
   }
 }
 
+/// Big integers are shown with digit grouping except in Raw and hex, in Eng
+/// and EngSi like integers in engineering notation
+#[cfg(feature = "bigint")]
+pub fn disp_big_z(big_z: &BigZ, disp: NumDisplay) -> String {
+  let r = || num_traits::ToPrimitive::to_f64(big_z).unwrap_or_default();
+  match disp {
+    Raw => format!("{big_z}"),
+    Eng(prec) => disp_eng(r(), prec, false),
+    EngSi(prec) => disp_eng(r(), prec, true),
+    // Grouped by the number format if it has groups, see `Native::disp()`
+    Std | Fix(..) | Sci(..) | Frac | Full => {
+      if context::get().format.group.is_some() {
+        format!("{big_z}")
+      } else {
+        group_digits(&format!("{big_z}"), '\'', 3)
+      }
+    }
+    HexU => format!("{big_z:X}"),
+    HexL => format!("{big_z:x}"),
+  }
+}
+
+/// Big quotients as `n/d` or in the mixed mode as `w n/d` if they fit, else
+/// approximately as reals. In Fix, Sci, Eng and EngSi like reals. Hex is not
+/// available for quotients.
+#[cfg(feature = "bigint")]
+pub fn disp_big_q(big_q: &BigQ, disp: NumDisplay) -> String {
+  let r = num_traits::ToPrimitive::to_f64(big_q).unwrap_or_default();
+  if matches!(disp, Fix(..) | Sci(..) | Eng(..) | EngSi(..)) {
+    return disp_r(r, disp);
+  }
+
+  let (numer, denom) = (big_q.numer(), big_q.denom());
+  let s = if is_mixed() && disp != Raw && numer.magnitude() > denom.magnitude()
+  {
+    let rest = (numer % denom).magnitude().clone();
+    format!("{} {rest}/{denom}", numer / denom)
+  } else {
+    format!("{numer}/{denom}")
+  };
+  if matches!(disp, Raw | Full) || crate::text::width(&s) <= NUM_WIDTH {
+    return s;
+  }
+
+  let disp = if matches!(disp, HexU | HexL) { Std } else { disp };
+  format!("≈{}", disp_r(r, disp))
+}
+
+/// Decimals in full if they fit, else like reals
+#[cfg(feature = "decimal")]
+pub fn disp_d(d: D, disp: NumDisplay) -> String {
+  let s = match disp {
+    Raw | Full => return d.normalize().to_string(),
+    Std => d.normalize().to_string(),
+    Fix(prec) => format!("{:.*}", prec as usize, d.round_dp(prec as u32)),
+    Sci(..) | Eng(..) | EngSi(..) | Frac | HexU | HexL => String::new(),
+  };
+
+  if s.is_empty() || crate::text::width(&s) > NUM_WIDTH {
+    disp_r(num_traits::ToPrimitive::to_f64(&d).unwrap_or_default(), disp)
+  } else {
+    s
+  }
+}
+
+/// Symbolic terms like `3π/4` in Std, Raw, Full and hex, else like reals
+pub fn disp_s(s: S, disp: NumDisplay) -> String {
+  match disp {
+    Std | Raw | Full | HexU | HexL => s.disp(),
+    _ => disp_r(s.as_r(), disp),
+  }
+}
+
+/// Reals with an uncertainty like `12.3 ± 0.4`, in Raw without spaces. Too
+/// wide like complex numbers, see `disp_c()`.
+pub fn disp_u(u: U, disp: NumDisplay) -> String {
+  fit_parts(disp, |disp| match disp {
+    Raw => format!("{}±{}", disp_r(u.mid, Raw), disp_r(u.rad, Raw)),
+    _ => format!("{} ± {}", disp_r(u.mid, disp), disp_r(u.rad, disp)),
+  })
+}
+
+/// Reals with a unit like `12.5 km/h`, in Raw with `_` before the unit
+pub fn disp_p(p: &P, disp: NumDisplay) -> String {
+  match disp {
+    Raw => format!("{}_{}", disp_r(p.value, Raw), p.unit),
+    _ => format!("{} {}", disp_r(p.value, disp), p.unit),
+  }
+}
+
+/// Separate groups of digits, for example `-1'234'567` with size 3
+pub fn group_digits(digits: &str, separator: char, size: usize) -> String {
+  let (sign, digits) = match digits.strip_prefix('-') {
+    Some(digits) => ("-", digits),
+    None => ("", digits),
+  };
+  let mut grouped = String::from(sign);
+  for (i, digit) in digits.chars().enumerate() {
+    if i > 0 && (digits.len() - i).is_multiple_of(size) {
+      grouped.push(separator);
+    }
+    grouped.push(digit);
+  }
+
+  grouped
+}
+
 fn disp_sci(r: R, prec: u8) -> String {
   let inf = if r > 0.0 { "+oo" } else { "-oo" }.into();
   let prec = prec as usize;
   let s = format!("{r:.prec$e}");
 
-  // If exponent >= 300, positive infinity, if <= -300, negative infinity
-  if let Some(e_pos) = s.find('e') {
-    let (_, exponent) = s.split_at(e_pos + 1);
-    if exponent.len() >= 3 {
-      let mut chars = exponent.chars();
-      match chars.next() {
-        Some('3') => return inf,
-        Some('-') => {
-          if let Some('3') = chars.next() {
-            return "0".into();
-          }
-        } // no -0.0
-        _ => (),
-      }
-    }
+  // If exponent >= 300, infinity, if <= -300, zero (no -0.0)
+  match s.split_once('e').map(|(_, exp)| exp.parse::<i32>()) {
+    Some(Ok(300..)) => inf,
+    Some(Ok(..=-300)) => "0".into(),
+    _ => s,
   }
+}
 
-  s
+/// The SI prefixes from 1e-24 to 1e24 in steps of 1e3
+const SI_PREFIXES: [&str; 17] = [
+  "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E",
+  "Z", "Y",
+];
+
+/// Engineering notation like Sci with prec + 1 significant digits, but the
+/// exponent is a multiple of three like `47.0e3`. With SI prefixes instead of
+/// the exponent like `47.0k`, beyond the prefixes with the exponent.
+fn disp_eng(r: R, prec: u8, si: bool) -> String {
+  // +oo, -oo or 0 beyond the exponent range
+  let s = disp_sci(r, prec);
+  let Some((mantissa, exp)) = s.split_once('e') else {
+    return s;
+  };
+  let exp: i32 = exp.parse().unwrap_or_default();
+  let shift = exp.rem_euclid(3) as usize;
+  let exp = exp - shift as i32;
+
+  // Move the point by shift digits to the right
+  let (sign, mantissa) = match mantissa.strip_prefix('-') {
+    Some(mantissa) => ("-", mantissa),
+    None => ("", mantissa),
+  };
+  let digits = format!("{:0<1$}", mantissa.replace('.', ""), shift + 1);
+  let (int, frac) = digits.split_at(shift + 1);
+  let point = if frac.is_empty() { "" } else { "." };
+
+  let in_si = (-24..=24).contains(&exp);
+  let prefix = in_si.then(|| SI_PREFIXES[(exp + 24) as usize / 3]);
+  match prefix {
+    Some(prefix) if si => {
+      format!("{sign}{int}{point}{frac}{prefix}")
+    }
+    _ => format!("{sign}{int}{point}{frac}e{exp}"),
+  }
 }
 
 /// Exponent in scientific notation, +/-INFINITY => i16::MAX, 0.0 or NaN => 0
@@ -63,6 +282,7 @@ pub fn is_disp_as_z(r: R) -> bool {
   !r.is_nan() && r.fract() == 0.0 && (0..10).contains(&exponent(r))
 }
 
+/// Raw rounds to the precision of the context, see `Context::precision`
 pub fn disp_raw(r: R) -> String {
   fn to_i_1(s: &str) -> Result<i32, ParseIntError> {
     d!("to_i_1 {s}");
@@ -72,9 +292,12 @@ pub fn disp_raw(r: R) -> String {
     s.trim_end_matches('0')
   }
 
-  const RAW_PRECISION: u8 = 14u8;
+  // Beyond the exponent range: +oo, -oo or 0
+  let s = disp_sci(r, precision() - 1);
+  if !s.contains('e') {
+    return s;
+  }
 
-  let s = disp_sci(r, RAW_PRECISION);
   if let Some(dot_pos) = s.find('.') {
     let (int, rest) = s.split_at(dot_pos);
     d!("int {int} rest {rest}");
@@ -94,50 +317,45 @@ pub fn disp_raw(r: R) -> String {
   unreachable!("exp not i32 or missing . or e: '{s}'");
 }
 
-/// Format r in standard notation when |r| between 1e-6 and 1e12 exclusive,
-/// rounding to 12 decimal digits precision, in scientific notation with
-/// 12 digits precision but trailing zeroes removed from mantissa. If the
-/// display gets wider than 17 chars, reduce precision.
+/// Format r with at most 12 significant digits, trailing zeros removed, in
+/// standard notation when |r| is from 1e-6 below 1e12, else in scientific
+/// notation like `disp_sci()`. If the display gets wider than 17 chars, reduce
+/// the significant digits.
 pub fn disp_std(r: R) -> String {
   const DISP_MAX_LEN: usize = 17;
 
-  // Format then trim zeroes from number or mantissa respectively. If the
-  // formatted results doesn't fit within DISP_MAX_LEN retry recursively
-  // with lower precision.
-  fn fmt_r<F: Fn(R, usize) -> String>(r: R, prec: usize, fmt: F) -> String {
-    let s = fmt(r, prec);
-    assert!(s.is_ascii(), "fmt() did not return an ASCII-only string");
-    let n = s.len();
-
-    // trim end zeroes from timmee (mantissa / number), tail is exp or empty
-    let pos = s.find('e').unwrap_or(n);
-    let (trimmee, tail) = s.split_at(pos);
-    let trimmee = trimmee.trim_end_matches('0').trim_end_matches('.');
-    let s_trimmed = [trimmee, tail].concat();
-    d!("fmt({r:e}, {prec}): s {s} n {n} pos {pos} s_trimmed {s_trimmed}");
-
-    let n_trimmed = s_trimmed.len();
-    if n_trimmed > DISP_MAX_LEN {
-      fmt_r(r, prec - (n_trimmed - DISP_MAX_LEN), fmt)
-    } else {
-      s_trimmed.to_owned()
+  let mut s = String::new();
+  for digits in (1..=12).rev() {
+    s = disp_sig(r, digits);
+    if s.len() <= DISP_MAX_LEN {
+      break;
     }
   }
+  s
+}
 
-  // Display standard notation without exponent if not too small nor too large,
-  // else use scientific notation but don't exceed DISP_MAX_LEN (see fmt_r())
-  if (1e-6..1e13).contains(&r.abs()) {
-    d!("standard notation");
-    fmt_r(r, 12, |r, prec| format!("{r:.prec$}"))
+// r with the significant digits, the exponent from the rounded mantissa like
+// 9.9999e12 rounded to 1e13, and the trailing zeros removed
+fn disp_sig(r: R, digits: usize) -> String {
+  let s = disp_sci(r, digits as u8 - 1);
+  let Some((mantissa, exp)) = s.split_once('e') else {
+    return s; // +oo, -oo or 0 beyond the exponent range
+  };
+  let exp: i32 = exp.parse().unwrap_or_default();
+  d!("disp_sig({r:e}, {digits}): {mantissa} e {exp}");
+
+  let trim = |s: &str| s.trim_end_matches('0').trim_end_matches('.').to_owned();
+  if (-6..12).contains(&exp) {
+    let decimals = (digits as i32 - 1 - exp).max(0) as usize;
+    let s = format!("{r:.decimals$}");
+    if s.contains('.') {
+      trim(&s)
+    } else {
+      s
+    }
   } else {
-    d!("scientific notation");
-    fmt_r(r, 12, |r, prec| format!("{r:.prec$e}"))
+    format!("{}e{exp}", trim(mantissa))
   }
-
-  // Todo: small bug, numbers like 99999999999.432 display too many digits of
-  // precision because format!() understands the precision as the number of
-  // digits after the point, and Flical understands the precision as the number
-  // of digits.
 }
 
 // Format the number in standard notation with a fixed precision. If it displays
@@ -174,23 +392,77 @@ pub fn disp_r(r: R, disp: NumDisplay) -> String {
     Std | HexL | HexU => disp_std(r),
     Fix(prec) => disp_fix(r, prec),
     Sci(prec) => disp_sci(r, prec),
+    Eng(prec) => disp_eng(r, prec, false),
+    EngSi(prec) => disp_eng(r, prec, true),
+    Frac => disp_frac(r),
+    Full => disp_sig(r, precision() as usize),
     Raw => disp_raw(r),
   }
 }
 
-pub fn disp_q(q: Q, _disp: NumDisplay) -> String {
-  // todo: omit / if denom == 1
-  let numer = q.numer();
-  let denom = q.denom();
-  format!("{numer}/{denom}")
+/// The nearest quotient with a denominator up to the context's maximum, with
+/// `≈` if not exact, see `Native::approx_q()`. Else like Std, also if the
+/// nearest quotient is 0.
+pub fn disp_frac(r: R) -> String {
+  match Native::approx_q(r, context::max_denom()) {
+    Some(q) if *q.numer() as R / *q.denom() as R == r => disp_q(q, Frac),
+    Some(q) if *q.numer() != 0 => format!("≈{}", disp_q(q, Frac)),
+    _ => disp_std(r),
+  }
 }
 
+/// Quotients like `19/8`, in the mixed mode except in Raw like `2 3/8`, and
+/// without the slash if the denominator is 1. In Fix, Sci, Eng and EngSi like
+/// reals. Hex is not available for quotients.
+pub fn disp_q(q: Q, disp: NumDisplay) -> String {
+  let (numer, denom) = (*q.numer(), *q.denom());
+  if denom != 1 && matches!(disp, Fix(..) | Sci(..) | Eng(..) | EngSi(..)) {
+    disp_r(numer as R / denom as R, disp)
+  } else if denom == 1 {
+    disp_z(numer, disp)
+  } else if is_mixed() && disp != Raw && numer.abs() > denom {
+    format!("{} {}/{denom}", numer / denom, (numer % denom).abs())
+  } else {
+    format!("{numer}/{denom}")
+  }
+}
+
+/// Complex numbers like `3i4`, or in the polar mode like `5∠0.927295218002`
+/// and in degrees like `5∠53.130102354156°`, both parts like reals. Too wide
+/// for a stack line, both parts are shown in Sci with as many decimals as fit.
 pub fn disp_c(c: C, disp: NumDisplay) -> String {
   if c.im == 0.0 {
-    disp_r(c.re, disp)
-  } else {
-    format!("{}i{}", disp_r(c.re, disp), disp_r(c.im, disp))
+    return disp_r(c.re, disp);
+  }
+
+  fit_parts(disp, |disp| {
+    if is_polar() && disp != Raw {
+      let (r, theta) = c.to_polar();
+      let (theta, deg) =
+        if is_degrees() { (theta.to_degrees(), "°") } else { (theta, "") };
+      format!("{}∠{}{deg}", disp_r(r, disp), disp_r(theta, disp))
+    } else {
+      let i = if disp == Raw { 'I' } else { 'i' };
+      format!("{}{i}{}", disp_r(c.re, disp), disp_r(c.im, disp))
+    }
+  })
+}
+
+// The display of a number with two real parts if it fits the stack line, else
+// in Sci with fewer decimals, in Sci(0) the widest is `-1e-300i-1e-300`
+fn fit_parts(disp: NumDisplay, parts: impl Fn(NumDisplay) -> String) -> String {
+  let s = parts(disp);
+  if matches!(disp, Raw | Full) || crate::text::width(&s) <= NUM_WIDTH {
+    return s;
   }
+
+  let prec = match disp {
+    Sci(prec) | Eng(prec) | EngSi(prec) => prec,
+    _ => precision() - 1,
+  };
+  let mut fitting = (0..prec).rev().map(|prec| parts(Sci(prec)));
+  let s = fitting.find(|s| crate::text::width(s) <= NUM_WIDTH);
+  s.unwrap_or_else(|| parts(Sci(0)))
 }
 
 #[cfg(test)]
@@ -219,6 +491,54 @@ mod tests {
   }
 
   // Test the precision of 15 decimal digits with rounding
+  #[test]
+  fn test_disp_eng() {
+    #[track_caller]
+    fn eng(r: R, prec: u8, eng: &str, si: &str) {
+      assert_eq!(disp_r(r, Eng(prec)), eng);
+      assert_eq!(disp_r(r, EngSi(prec)), si);
+    }
+
+    eng(4.7e3, 1, "4.7e3", "4.7k");
+    eng(2.2e-7, 1, "220e-9", "220n");
+    eng(47e3, 2, "47.0e3", "47.0k");
+    eng(-0.012345, 3, "-12.35e-3", "-12.35m");
+    eng(1.5e-6, 0, "2e-6", "2µ");
+    eng(0.5, 2, "500e-3", "500m");
+    eng(1.25, 2, "1.25e0", "1.25");
+    eng(123456.0, 3, "123.5e3", "123.5k");
+    eng(3e27, 2, "3.00e27", "3.00e27");
+    eng(2e-28, 0, "200e-30", "200e-30");
+    eng(R::INFINITY, 2, "+oo", "+oo");
+
+    assert_eq!(disp_z(42, Eng(2)), "42");
+    assert_eq!(disp_z(-4700, EngSi(1)), "-4.7k");
+    assert_eq!(disp_z(4700, Sci(1)), "4700");
+  }
+
+  #[test]
+  fn test_disp_frac() {
+    use crate::context::{Context, ContextGuard};
+
+    assert_eq!(disp_r(0.375, Frac), "3/8");
+    assert_eq!(disp_r(-0.1, Frac), "-1/10");
+    assert_eq!(disp_r(1.0 / 3.0, Frac), "1/3");
+    assert_eq!(disp_r(0.333333333333, Frac), "≈1/3");
+    assert_eq!(disp_r(std::f64::consts::PI, Frac), "≈833719/265381");
+    assert_eq!(disp_r(2.5e7, Frac), "25000000");
+    assert_eq!(disp_r(2.5e-7, Frac), "2.5e-7");
+    assert_eq!(disp_r(1234567.5, Frac), "1234567.5");
+
+    let _context = ContextGuard::new(Context {
+      max_denom: 64,
+      mixed: true,
+      ..Context::default()
+    });
+    assert_eq!(disp_r(std::f64::consts::PI, Frac), "≈3 9/64");
+    assert_eq!(disp_r(0.3, Frac), "3/10");
+    assert_eq!(disp_r(0.33, Frac), "≈21/64");
+  }
+
   #[test]
   fn test_r_raw_precision() {
     assert_eq!(disp_r(1.23456789012345, Raw), ".123456789012345E1");
@@ -233,21 +553,87 @@ mod tests {
     assert_eq!(disp_r(0.2222222222222277e-99, Raw), ".222222222222228E-99");
   }
 
+  // Raw rounds to the precision of the context
+  #[test]
+  fn test_precisions() {
+    use crate::context::{Context, ContextGuard, PRECISIONS};
+
+    #[rustfmt::skip]
+    let raws = [
+      (10, ".6666666667E0", ".123456789E1", "-.123456789E-4"),
+      (11, ".66666666667E0", ".12345678901E1", "-.12345678901E-4"),
+      (12, ".666666666667E0", ".123456789012E1", "-.123456789012E-4"),
+      (13, ".6666666666667E0", ".1234567890123E1", "-.1234567890123E-4"),
+      (14, ".66666666666667E0", ".12345678901235E1", "-.12345678901235E-4"),
+      (15, ".666666666666667E0", ".123456789012346E1", "-.123456789012345E-4"),
+    ];
+    assert_eq!(raws.len(), PRECISIONS.len());
+
+    for (precision, two_thirds, r, negative) in raws {
+      let _context =
+        ContextGuard::new(Context { precision, ..Context::default() });
+      assert_eq!(disp_r(2.0 / 3.0, Raw), two_thirds, "{precision}");
+      assert_eq!(disp_r(1.234567890123456, Raw), r, "{precision}");
+      assert_eq!(disp_r(-1.23456789012345e-5, Raw), negative, "{precision}");
+      assert_eq!(disp_r(0.1 + 0.2, Raw), ".3E0", "{precision}");
+    }
+  }
+
+  #[test]
+  fn test_disp_q() {
+    use crate::context::{Context, ContextGuard};
+
+    assert_eq!(disp_q(Q::new(19, 8), Std), "19/8");
+    assert_eq!(disp_q(Q::new_raw(5, 1), Std), "5");
+    let _context =
+      ContextGuard::new(Context { mixed: true, ..Context::default() });
+    assert_eq!(disp_q(Q::new(19, 8), Std), "2 3/8");
+    assert_eq!(disp_q(Q::new(-19, 8), Std), "-2 3/8");
+    assert_eq!(disp_q(Q::new(-3, 8), Std), "-3/8");
+    assert_eq!(disp_q(Q::new(19, 8), Raw), "19/8");
+  }
+
+  #[test]
+  fn test_disp_c_polar() {
+    use crate::context::{Context, ContextGuard};
+
+    let c = C::new(3.0, 4.0);
+    assert_eq!(disp_c(c, Std), "3i4");
+    let context =
+      ContextGuard::new(Context { polar: true, ..Context::default() });
+    assert_eq!(disp_c(c, Std), "5∠0.927295218002");
+    assert_eq!(disp_c(c, Fix(2)), "5∠0.93");
+    assert_eq!(disp_c(c, Raw), "3I4");
+    context.install(Context {
+      polar: true,
+      degrees: true,
+      ..Context::default()
+    });
+    assert_eq!(disp_c(c, Std), "5∠53.1301023542°");
+    assert_eq!(disp_c(C::new(-2.0, 0.0), Std), "-2");
+  }
+
   #[test]
   fn test_r_std_precision() {
     assert_eq!(disp_r(0.45, Std), "0.45");
     assert_eq!(disp_r(0.123456781234, Std), "0.123456781234");
     assert_eq!(disp_r(0.1234567812365, Std), "0.123456781237");
-    assert_eq!(disp_r(-9999999999999.4, Std), "-9999999999999.4");
-    assert_eq!(disp_r(-9999999999999.42, Std), "-9999999999999.42");
-    assert_eq!(disp_r(12345678901234.0, Std), "1.234567890123e13");
+    assert_eq!(disp_r(99999999999.432, Std), "99999999999.4");
+    assert_eq!(disp_r(-999999999999.4, Std), "-999999999999");
+    assert_eq!(disp_r(-999999999999.6, Std), "-1e12");
+    assert_eq!(disp_r(-9999999999999.4, Std), "-1e13");
+    assert_eq!(disp_r(-9999999999999.42, Std), "-1e13");
+    assert_eq!(disp_r(12345678901234.0, Std), "1.23456789012e13");
     assert_eq!(disp_r(-1.2345678901e-200, Std), "-1.23456789e-200");
     assert_eq!(disp_r(-1.2345678916e-200, Std), "-1.234567892e-200");
-
-    // small bug: if Std uses sci, should route through disp_sci() instead
-    // assert_eq!(disp_r(1e300, Std), "+oo");
-    // assert_eq!(disp_r(1e-300, Std), "+oo");
-    // todo something like 0.9999999e300
+    assert_eq!(disp_r(0.0000012345678901234, Std), "0.00000123456789");
+    assert_eq!(disp_r(9.999999999999e-7, Std), "0.000001");
+    assert_eq!(disp_r(6.7654957011945e-39, Std), "6.76549570119e-39");
+
+    assert_eq!(disp_r(1e300, Std), "+oo");
+    assert_eq!(disp_r(1e-300, Std), "0");
+    assert_eq!(disp_r(9.999999e299, Std), "9.999999e299");
+    assert_eq!(disp_r(-9.9999999999999e299, Std), "-oo");
   }
 
   #[test]
@@ -275,11 +661,39 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_group_digits() {
+    assert_eq!(group_digits("1", '\'', 3), "1");
+    assert_eq!(group_digits("123", '\'', 3), "123");
+    assert_eq!(group_digits("1234", '\'', 3), "1'234");
+    assert_eq!(group_digits("-123456", ' ', 3), "-123 456");
+    assert_eq!(group_digits("-1234567", '\'', 3), "-1'234'567");
+  }
+
+  #[test]
+  fn test_num_format() {
+    let [none, apos, comma, space, dot] = NumFormat::PRESETS;
+    assert_eq!(none.apply("1234567.89"), "1234567.89");
+    assert_eq!(apos.apply("1234567.89"), "1'234'567.89");
+    assert_eq!(comma.apply("-1234.5678"), "-1,234.5678");
+    assert_eq!(space.apply("1234567.89"), "1 234 567,89");
+    assert_eq!(dot.apply("1234567.89"), "1.234.567,89");
+    assert_eq!(apos.apply("1.2345e-1234"), "1.2345e-1234");
+    assert_eq!(space.apply("12345.6±0.1234"), "12 345,6±0,1234");
+    assert_eq!(apos.apply("12345i-67890"), "12'345i-67'890");
+    assert_eq!(apos.apply("12345/67890"), "12'345/67'890");
+
+    let four = NumFormat { group_size: 4, ..apos };
+    assert_eq!(four.apply("123456789"), "1'2345'6789");
+    assert_eq!(dot.next(), none);
+    assert_eq!(four.next(), none);
+  }
+
   #[test]
   fn test_weird_zeros() {
     let subnormal = f64::MIN_POSITIVE / 2.0;
     assert!(subnormal.is_subnormal(), "subormality sanity check");
-    // assert_eq!(disp_r(subnormal, Std), "0"); todo Std should use disp_sci()
+    assert_eq!(disp_r(subnormal, Std), "0");
 
     let neg_zero = -0.0f64;
     assert!(neg_zero.is_sign_negative(), "negative zero sanity check");
@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::native::types::*;
+use crate::native::{Native, NativeError};
+use crate::Num;
+
+/// The most digits of the numerator, the whole part and the denominator of
+/// quotients and mixed numbers, see also `MAX_DENOMS`
+pub const QUOTIENT_DIGITS: u8 = 6;
+
+/// The most digits of an exponent, more would just give infinity or zero
+pub const EXPONENT_DIGITS: u8 = 3;
+
+/// The most hex digits, the 64 bits of an integer
+pub const HEX_DIGITS: u8 = 16;
+
+/// Where the entry of a real is: before it, after its sign, in its integer
+/// part with the count of digits, at a decimal point without digits before,
+/// in its decimals, after the e or the sign of the exponent, or in the
+/// exponent with the count of digits
+#[derive(Copy, Clone, Debug, Default, PartialEq)] #[rustfmt::skip]
+pub enum Real { #[default] Start, Sign, Int(u8), Point, Frac, E, ESign, Exp(u8) }
+use Real::*;
+
+/// The state machine of the number entry: the real part or a simple real,
+/// the imaginary part after `i`, the angle θ after `∠`, the uncertainty after
+/// `±`, the numerator of a mixed number after its whole part, the
+/// denominator after `/` and the hex digits after `0x`. The digits count.
+#[derive(Copy, Clone, Debug, PartialEq)] #[rustfmt::skip]
+pub enum Entry {
+  Re(Real), Im(Real), Theta(Real), Uncertainty(Real), Numer(u8), Denom(u8),
+  Hex(u8),
+}
+use Entry::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)] #[rustfmt::skip]
+pub enum EntryError {
+  Misplaced(char), Duplicate(char), ExpFraction, ExpDigits, Numerator,
+  Denominator, Fraction, Pm, Angle, PolarInfinity, HexDigits,
+}
+
+impl fmt::Display for EntryError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      EntryError::Misplaced(c) => write!(f, "`{c}` not possible here"),
+      EntryError::Duplicate(c) => write!(f, "duplicate `{c}`"),
+      EntryError::ExpFraction => write!(f, "no exponent for fractions"),
+      EntryError::ExpDigits => write!(f, "exponent too long"),
+      EntryError::Numerator => write!(f, "numerator too long"),
+      EntryError::Denominator => write!(f, "denominator zero or too long"),
+      EntryError::Fraction => write!(f, "already a fraction"),
+      EntryError::Pm => write!(f, "`±` only after a real"),
+      EntryError::Angle => write!(f, "`∠` only after a real"),
+      EntryError::PolarInfinity => write!(f, "no polar form of ∞"),
+      EntryError::HexDigits => write!(f, "hex too long"),
+    }
+  }
+}
+
+impl Error for EntryError {}
+
+impl Real {
+  fn next(self, c: char) -> Result<Real, EntryError> {
+    Ok(match (self, c) {
+      (Start, '-') => Sign,
+      (Start | Sign, '.') => Point,
+      (Start | Sign, '0'..='9') => Int(1),
+      (Int(n), '0'..='9') => Int(n.saturating_add(1)),
+      (Int(_), '.') => Frac,
+      (Point | Frac, '0'..='9') => Frac,
+      (Int(_) | Frac, 'e') => E,
+      (E, '-') => ESign,
+      (E | ESign, '0'..='9') => Exp(1),
+      (Exp(n), '0'..='9') if n < EXPONENT_DIGITS => Exp(n + 1),
+      (Exp(_), '0'..='9') => return Err(EntryError::ExpDigits),
+      (E | ESign | Exp(_), 'e') | (Point | Frac, '.') => {
+        return Err(EntryError::Duplicate(c))
+      }
+      _ => return Err(EntryError::Misplaced(c)),
+    })
+  }
+
+  /// Whether the real has digits and doesn't end in the e of the exponent
+  fn is_complete(self) -> bool {
+    matches!(self, Int(_) | Frac | Exp(_))
+  }
+}
+
+impl Entry {
+  /// The state at the start of the input and the rest of the input to feed
+  /// into the state machine: hex digits after the prefix `0x`, else a real
+  fn start(input: &str) -> (Entry, &str) {
+    let unsigned = input.strip_prefix('-').unwrap_or(input);
+    match unsigned.strip_prefix("0x") {
+      Some(digits) => (Hex(0), digits),
+      None => (Re(Start), input),
+    }
+  }
+
+  pub fn next(self, c: char) -> Result<Entry, EntryError> {
+    use EntryError::*;
+
+    Ok(match (self, c) {
+      (Re(re), 'i') if re == Start || re.is_complete() => Im(Start),
+      (Re(re), '±') if re.is_complete() => Uncertainty(Start),
+      (Re(re), '∠') if re.is_complete() => Theta(Start),
+      (Re(Int(n)), '/' | ' ') if n > QUOTIENT_DIGITS => return Err(Numerator),
+      (Re(Int(_)), '/') => Denom(0),
+      (Re(Int(_)), ' ') => Numer(0),
+      (Numer(n), '0'..='9') if n < QUOTIENT_DIGITS => Numer(n + 1),
+      (Numer(_), '0'..='9') => return Err(Numerator),
+      (Numer(n), '/') if n > 0 => Denom(0),
+      (Denom(0), '0') => return Err(Denominator),
+      (Denom(n), '0'..='9') if n < QUOTIENT_DIGITS => Denom(n + 1),
+      (Denom(_), '0'..='9') => return Err(Denominator),
+      (Numer(_) | Denom(_), 'e') => return Err(ExpFraction),
+      (Numer(_) | Denom(_), '.' | '/') => return Err(Fraction),
+      (Hex(n), '0'..='9' | 'A'..='F') if n < HEX_DIGITS => Hex(n + 1),
+      (Hex(_), '0'..='9' | 'A'..='F') => return Err(HexDigits),
+      (Im(_), 'i') => return Err(Duplicate('i')),
+      (Uncertainty(_), 'i') | (_, '±') => return Err(Pm),
+      (Theta(_), 'i') | (_, '∠') => return Err(Angle),
+      (Re(re), c) => Re(re.next(c)?),
+      (Im(im), c) => Im(im.next(c)?),
+      (Theta(angle), c) => Theta(angle.next(c)?),
+      (Uncertainty(rad), c) => Uncertainty(rad.next(c)?),
+      (_, c) => return Err(Misplaced(c)),
+    })
+  }
+}
+
+/// Run the state machine over the input and return its state at the end or
+/// the first error. The input may be incomplete like `2/` or `1e-`. The
+/// modulus and the angle of the polar form are finite, ∞ has no direction.
+pub fn check(input: &str) -> Result<Entry, EntryError> {
+  let (entry, rest) = Entry::start(input);
+  let entry = rest.chars().try_fold(entry, Entry::next)?;
+  if let Some((r, theta)) = input.split_once('∠') {
+    // An incomplete part like `1e-` is no real yet but will be finite
+    let finite = |s: &str| s.parse::<R>().map_or(true, R::is_finite);
+    if !finite(r) || !finite(theta) {
+      return Err(EntryError::PolarInfinity);
+    }
+  }
+  Ok(entry)
+}
+
+/// Decode the input checked by the state machine. Empty parts are zero, for
+/// example `i5` is 5i and `1e` is 1, only an empty denominator is one. A mixed
+/// number needs its denominator, `2 3` or `2 3/` is no number.
+pub fn decode(input: &str) -> Result<Num, NativeError> {
+  let error = |err: EntryError| NativeError(err.to_string());
+  let entry = check(input).map_err(error)?;
+  let mut input = input.to_string();
+  if input.starts_with('i') {
+    input.insert(0, '0');
+  }
+  match entry {
+    Re(part) | Im(part) | Theta(part) | Uncertainty(part)
+      if !part.is_complete() =>
+    {
+      input.push('0')
+    }
+    Numer(0) => input.push_str("0/1"),
+    Numer(_) => return Err(error(EntryError::Denominator)),
+    Denom(0) if input.contains(' ') => {
+      return Err(error(EntryError::Denominator))
+    }
+    Denom(0) => input.push('1'),
+    Hex(0) => input.push('0'),
+    _ => (),
+  }
+
+  if let Some((sign, digits)) = input.split_once("0x") {
+    // Parse as unsigned then cast to signed like the repr of hex integers
+    let z = u64::from_str_radix(digits, 16)? as Z;
+    let z = if sign.is_empty() { z } else { z.wrapping_neg() };
+    return Ok(Native::from(z).into());
+  }
+
+  input.parse()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::NumDisplay::Std;
+
+  fn err(input: &str) -> EntryError {
+    check(input).unwrap_err()
+  }
+
+  fn decode(input: &str) -> String {
+    super::decode(input).unwrap().disp(Std)
+  }
+
+  #[test]
+  fn test_check() {
+    assert_eq!(check("-12.5e-3"), Ok(Re(Exp(1))));
+    assert_eq!(check("2 3/"), Ok(Denom(0)));
+    assert_eq!(check("i"), Ok(Im(Start)));
+    assert_eq!(check("1∠-3."), Ok(Theta(Frac)));
+    assert_eq!(check("-0x2AE"), Ok(Hex(3)));
+
+    assert_eq!(err("1e5e"), EntryError::Duplicate('e'));
+    assert_eq!(err("1.2."), EntryError::Duplicate('.'));
+    assert_eq!(err("1i2i"), EntryError::Duplicate('i'));
+    assert_eq!(err("e"), EntryError::Misplaced('e'));
+    assert_eq!(err("1e1234"), EntryError::ExpDigits);
+    assert_eq!(err("1234567/"), EntryError::Numerator);
+    assert_eq!(err("2/0"), EntryError::Denominator);
+    assert_eq!(err("2/3e"), EntryError::ExpFraction);
+    assert_eq!(err("2 3/4."), EntryError::Fraction);
+    assert_eq!(err("±"), EntryError::Pm);
+    assert_eq!(err("1i2∠"), EntryError::Angle);
+    assert_eq!(err("9e999∠"), EntryError::PolarInfinity);
+    assert_eq!(err("-1∠9e999"), EntryError::PolarInfinity);
+    assert_eq!(err("0x12345678123456789"), EntryError::HexDigits);
+    assert_eq!(err("0x1.5"), EntryError::Misplaced('.'));
+  }
+
+  #[test]
+  fn test_decode() {
+    assert_eq!(decode("-12.5e-3"), "-0.0125");
+    assert_eq!(decode("1e"), "1");
+    assert_eq!(decode("-"), "0");
+    assert_eq!(decode("."), "0");
+    assert_eq!(decode("2/"), "2");
+    assert_eq!(decode("2 3/8"), "19/8");
+    assert_eq!(decode("-2 "), "-2");
+    assert!(super::decode("-2 3").is_err());
+    assert!(super::decode("2 3/").is_err());
+    assert_eq!(decode("i5"), "0i5");
+    assert_eq!(decode("3i-"), "3");
+    assert_eq!(decode("12±"), "12");
+    assert_eq!(decode("0x"), "0");
+    assert_eq!(decode("-0x2A"), "-42");
+    assert_eq!(decode("0xFFFFFFFFFFFFFFFF"), "-1");
+    assert_eq!(decode("1e999"), "+oo");
+    assert!(super::decode(&"9".repeat(400)).is_ok());
+    assert!(super::decode("1e2e").is_err());
+  }
+
+  #[test]
+  fn test_check_decode() {
+    // Every input up to 7 chars passing the check decodes, built by adding
+    // chars to inputs passing the check. Only mixed numbers without
+    // denominator are no number.
+    let mut inputs = vec![String::new()];
+    let denominator = EntryError::Denominator.to_string();
+    while let Some(input) = inputs.pop() {
+      if let Err(err) = super::decode(&input) {
+        let mixed = input.contains(' ') && err.0 == denominator;
+        assert!(mixed, "`{input}`: {err}");
+      }
+      if input.chars().count() < 7 {
+        for c in "09.-e i±∠/xAF".chars() {
+          let input = format!("{input}{c}");
+          if check(&input).is_ok() {
+            inputs.push(input);
+          }
+        }
+      }
+    }
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
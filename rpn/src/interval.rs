@@ -0,0 +1,258 @@
+use crate::native::types::*;
+use crate::native::NativeError;
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::ops::Neg;
+
+/// A value with an uncertainty like `12.3 ± 0.4`, this means the interval from
+/// 11.9 to 12.7. Operations propagate the uncertainty: the result is the
+/// operation on the values, and the uncertainty is the largest deviation of
+/// the operation in the intervals, at their bounds or at the extrema in
+/// between. Poles in the intervals and bounds outside of the domain give an
+/// infinite uncertainty. Only if the value itself is outside of the domain the
+/// operations give `None`, and the caller calculates with the value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+  pub mid: R,
+  pub rad: R,
+}
+
+impl Interval {
+  pub fn new(mid: R, rad: R) -> Self {
+    Interval { mid, rad: rad.abs() }
+  }
+
+  pub fn lo(self) -> R {
+    self.mid - self.rad
+  }
+
+  pub fn hi(self) -> R {
+    self.mid + self.rad
+  }
+
+  /// Is zero in the interval?
+  pub fn contains_0(self) -> bool {
+    self.lo() <= 0.0 && 0.0 <= self.hi()
+  }
+
+  /// Is one of the points `offset + k · period` with an integer k in the
+  /// interval?
+  fn contains_any(self, offset: R, period: R) -> bool {
+    let k = ((self.lo() - offset) / period).ceil();
+    offset + k * period <= self.hi()
+  }
+
+  /// The operation `+`, `-`, `*` or `/`. Dividing by an interval with zero
+  /// has an infinite uncertainty.
+  pub fn op(self, op: &str, rhs: Self) -> Option<Self> {
+    let f: fn(R, R) -> R = match op {
+      "+" => |x, y| x + y,
+      "-" => |x, y| x - y,
+      "*" => |x, y| x * y,
+      "/" => |x, y| x / y,
+      _ => return None,
+    };
+
+    let mid = f(self.mid, rhs.mid);
+    if op == "/" && rhs.contains_0() {
+      return Some(Interval::new(mid, R::INFINITY));
+    }
+
+    let (lo, hi) = (rhs.lo(), rhs.hi());
+    let corners = [self.lo(), self.hi()].map(|x| [f(x, lo), f(x, hi)]);
+    Self::with_bounds(mid, corners.as_flattened())
+  }
+
+  /// The monotonic function on the interval like `sqrt` or `ln`, the
+  /// uncertainty is the largest deviation at the bounds. Functions with
+  /// extrema or poles need their own method like `sin()` or `tan()`.
+  pub fn map(self, f: fn(R) -> R) -> Option<Self> {
+    self.map_extrema(f, [])
+  }
+
+  /// The function with the extreme values in the interval as well
+  fn map_extrema(
+    self,
+    f: fn(R) -> R,
+    extrema: impl IntoIterator<Item = R>,
+  ) -> Option<Self> {
+    let mut bounds = vec![f(self.lo()), f(self.hi())];
+    bounds.extend(extrema);
+    Self::with_bounds(f(self.mid), &bounds)
+  }
+
+  /// The function with the period 2π and its extrema `(x, f(x))`
+  fn map_periodic(self, f: fn(R) -> R, extrema: &[(R, R)]) -> Option<Self> {
+    let extrema =
+      extrema.iter().filter(|(x, _)| self.contains_any(*x, 2.0 * PI));
+    self.map_extrema(f, extrema.map(|&(_, y)| y))
+  }
+
+  pub fn sin(self) -> Option<Self> {
+    self.map_periodic(R::sin, &[(FRAC_PI_2, 1.0), (-FRAC_PI_2, -1.0)])
+  }
+
+  pub fn cos(self) -> Option<Self> {
+    self.map_periodic(R::cos, &[(0.0, 1.0), (PI, -1.0)])
+  }
+
+  /// The tangent, with a pole at π/2 + kπ the uncertainty is infinite
+  pub fn tan(self) -> Option<Self> {
+    match self.contains_any(FRAC_PI_2, PI) {
+      true => Self::with_bounds(self.mid.tan(), &[R::INFINITY]),
+      false => self.map(R::tan),
+    }
+  }
+
+  /// The fractional part jumps at the integers: from 1 to 0 at the positive
+  /// ones and from 0 to -1 at the negative ones
+  pub fn fract(self) -> Option<Self> {
+    let positive = self.hi().floor() >= self.lo().ceil().max(1.0);
+    let negative = self.lo().ceil() <= self.hi().floor().min(-1.0);
+    let mut extrema = vec![];
+    if positive {
+      extrema.extend([1.0, 0.0]);
+    }
+    if negative {
+      extrema.extend([0.0, -1.0]);
+    }
+    self.map_extrema(R::fract, extrema)
+  }
+
+  /// The power x ^ exp is monotonic in x and in exp for x > 0, and has a pole
+  /// at x = 0 for negative exponents
+  pub fn pow(self, exp: Self) -> Option<Self> {
+    let (lo, hi) = (exp.lo(), exp.hi());
+    let mut bounds = vec![];
+    for x in [self.lo(), self.hi()] {
+      bounds.extend([x.powf(lo), x.powf(hi)]);
+    }
+    if self.contains_0() {
+      bounds.extend([R::powf(0.0, lo), R::powf(0.0, hi)]);
+    }
+    Self::with_bounds(self.mid.powf(exp.mid), &bounds)
+  }
+
+  /// The logarithm of x to the base, a base around 1 divides by an interval
+  /// with zero
+  pub fn log(self, base: Self) -> Option<Self> {
+    self.map(R::ln)?.op("/", base.map(R::ln)?)
+  }
+
+  // The uncertainty is the largest deviation from the value, infinite if a
+  // bound is outside of the domain. `None` if the value is.
+  fn with_bounds(mid: R, bounds: &[R]) -> Option<Self> {
+    if mid.is_nan() {
+      return None;
+    }
+
+    let rad = match bounds.iter().any(|r| r.is_nan()) {
+      true => R::INFINITY,
+      false => bounds.iter().map(|r| (r - mid).abs()).fold(0.0, R::max),
+    };
+    Some(Interval::new(mid, rad))
+  }
+
+  pub fn abs(self) -> Self {
+    Interval { mid: self.mid.abs(), ..self }
+  }
+
+  pub fn recip(self) -> Self {
+    Interval::new(1.0, 0.0).op("/", self).unwrap_or(self)
+  }
+
+  /// Parse the value and the uncertainty separated by `±`. A missing
+  /// uncertainty as typed by the user is zero.
+  pub fn parse(s: &str) -> Result<Self, NativeError> {
+    let (mid, rad) = s
+      .split_once('±')
+      .ok_or_else(|| NativeError(format!("no uncertainty in `{s}`")))?;
+    let rad = if rad.trim().is_empty() { 0.0 } else { parse_r(rad.trim())? };
+
+    Ok(Interval::new(parse_r(mid.trim())?, rad))
+  }
+}
+
+// Reals and the infinities +oo and -oo
+fn parse_r(s: &str) -> Result<R, NativeError> {
+  Ok(match s {
+    "+oo" => R::INFINITY,
+    "-oo" => -R::INFINITY,
+    _ => s.parse()?,
+  })
+}
+
+impl Neg for Interval {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Interval { mid: -self.mid, ..self }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn u(s: &str) -> Interval {
+    Interval::parse(s).unwrap()
+  }
+
+  fn close(x: Interval, mid: R, rad: R) -> bool {
+    (x.mid - mid).abs() < 1e-12 && (x.rad - rad).abs() < 1e-12
+  }
+
+  #[test]
+  fn test_parse() {
+    assert_eq!(u("12.3±0.4"), Interval::new(12.3, 0.4));
+    assert_eq!(u("-1e3 ± -2"), Interval::new(-1000.0, 2.0));
+    assert!(Interval::parse("12.3").is_err());
+    assert_eq!(u("12.3±"), Interval::new(12.3, 0.0));
+    assert!(Interval::parse("±1").is_err());
+    assert_eq!(u("1±+oo"), Interval::new(1.0, R::INFINITY));
+  }
+
+  #[test]
+  fn test_op() {
+    let x = u("12.3±0.4");
+    assert!(close(x.op("+", u("1±0.1")).unwrap(), 13.3, 0.5));
+    assert!(close(x.op("-", u("1±0.1")).unwrap(), 11.3, 0.5));
+    assert!(close(x.op("*", u("2±0")).unwrap(), 24.6, 0.8));
+    assert!(close(u("10±1").op("*", u("10±1")).unwrap(), 100.0, 21.0));
+    assert!(close(u("10±0").op("/", u("4±1")).unwrap(), 2.5, 10.0 / 3.0 - 2.5));
+    assert_eq!(u("1±0").op("/", u("0.5±1")).unwrap().rad, R::INFINITY);
+  }
+
+  #[test]
+  fn test_map() {
+    assert!(close(u("4±1").map(R::sqrt).unwrap(), 2.0, 2.0 - 3f64.sqrt()));
+    assert!(close(u("-2±1").abs(), 2.0, 1.0));
+    assert_eq!(u("0±1").map(R::sqrt).unwrap().rad, R::INFINITY);
+    assert_eq!(u("-1±0.5").map(R::sqrt), None);
+  }
+
+  #[test]
+  fn test_extrema() {
+    assert!(close(u("0±4").sin().unwrap(), 0.0, 1.0));
+    assert!(close(u("0±0.5").cos().unwrap(), 1.0, 1.0 - 0.5f64.cos()));
+    assert!(close(u("0±4").cos().unwrap(), 1.0, 2.0));
+    assert_eq!(u("1.5±0.1").tan().unwrap().rad, R::INFINITY);
+    assert!(close(
+      u("1±0.5").tan().unwrap(),
+      1f64.tan(),
+      1.5f64.tan() - 1f64.tan()
+    ));
+    assert!(close(u("2±0.25").fract().unwrap(), 0.0, 1.0));
+    assert!(close(u("2.5±0.25").fract().unwrap(), 0.5, 0.25));
+  }
+
+  #[test]
+  fn test_pow_log() {
+    assert!(close(u("3±1").pow(u("2±0")).unwrap(), 9.0, 7.0));
+    assert!(close(u("2±0").pow(u("3±1")).unwrap(), 8.0, 8.0));
+    assert_eq!(u("0.5±1").pow(u("-1±0")).unwrap().rad, R::INFINITY);
+    assert!(close(u("100±0").log(u("10±0")).unwrap(), 2.0, 0.0));
+    assert_eq!(u("2±0").log(u("1±0.5")).unwrap().rad, R::INFINITY);
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
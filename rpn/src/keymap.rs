@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::calc::is_command;
+
+/// Keyboard keys as sent by the frontend: `ev.key` without the `Arrow` prefix
+/// and with `C-` for Ctrl and `A-` for Alt, for example `"A-s"` or `"Up"`.
+#[rustfmt::skip]
+pub static DEFAULT_KEYS: &[(&str, &str)] = &[
+  ("a", "A"),        ("b", "B"),       ("c", "C"),        ("d", "D"),
+  ("e", "E"),        ("f", "F"),
+  ("Enter", "ENTER"),("S", "STO"),     ("R", "RCL"),      ("Bspc", "DEL"),
+  ("+", "ADD"),      ("7", "7"),       ("8", "8"),        ("9", "9"),
+  ("-", "SUB"),      ("4", "4"),       ("5", "5"),        ("6", "6"),
+  ("*", "MUL"),      ("1", "1"),       ("2", "2"),        ("3", "3"),
+  ("/", "DIV"),      (".", "DOT"),     ("0", "0"),        ("A", "ALT"),
+
+  ("D", "DISP"),     ("A-y", "XY"),    ("Up", "R_UP"),    ("u", "UNDO"),
+  ("A-+", "EDATA"),  ("A-s", "SIN"),   ("A-c", "COS"),    ("A-t", "TAN"),
+  ("A--", "CHS"),    ("A-n", "LN"),    ("A-d", "LD"),     ("A-l", "LOG"),
+  ("!", "FAC"),      ("r", "ROOT"),    ("v", "SQRT"),     ("A-h", "TO_HMS"),
+  ("A-/", "RECIP"),  ("A-i", "INT"),   ("i", "I"),        ("I", "INV"),
+
+  ("M", "MACRO"),    ("A-z", "XZ"),    ("Down", "R_DOWN"),
+  ("A-r", "RAND"),   ("A-S", "ASIN"),  ("A-C", "ACOS"),   ("A-T", "ATAN"),
+  ("A-a", "ABS"),    ("A-N", "EXP"),   ("A-b", "LB"),     ("H", "H"),
+  ("A-P", "DPERC"),  ("^", "POW"),     ("C-2", "SQR"),    ("A-H", "TO_H"),
+  ("p", "PERC"),     ("A-f", "FRAC"),  ("[", "ROUND"),    ("Esc", "BASE"),
+
+  ("Tab", "META"),    ("K", "CONF"),     ("%", "MOD"),     ("G", "GCD"),
+  ("Q", "EXACT"),    ("A-.", "DECIMAL"), ("P", "PREC"),      ("A-p", "PI"),
+  ("~", "PM"),       ("U", "CONVERT"),  ("C", "CATALOG"),  ("L", "LAST_X"),
+  ("<", "ANGLE"),    ("A-g", "DEG"),    ("A-o", "POLAR"),  ("A-j", "CONJ"),
+  ("A-,", "FORMAT"),  ("A-m", "MIXED"),  ("A-v", "SHOW"),   ("Left", "LEFT"),
+  ("Right", "RIGHT"),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeymapError(pub String);
+
+impl fmt::Display for KeymapError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl Error for KeymapError {}
+
+/// Maps keyboard keys to commands. All commands are validated against the
+/// command registry (see `calc::is_command()`), so a keymap can't contain
+/// commands which don't exist.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keymap(HashMap<String, String>);
+
+impl Default for Keymap {
+  fn default() -> Self {
+    let keys = DEFAULT_KEYS.iter().map(|&(k, c)| (k.into(), c.into()));
+    Keymap(keys.collect())
+  }
+}
+
+impl Keymap {
+  /// The command for the key, if mapped
+  pub fn get(&self, key: &str) -> Option<&str> {
+    self.0.get(key).map(String::as_str)
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.0.iter().map(|(key, command)| (key.as_str(), command.as_str()))
+  }
+
+  /// Map key to command, an empty command removes the mapping
+  pub fn bind(&mut self, key: &str, command: &str) -> Result<(), KeymapError> {
+    if command.is_empty() {
+      self.0.remove(key);
+    } else if is_command(command) {
+      self.0.insert(key.into(), command.into());
+    } else {
+      return Err(KeymapError(format!("unknown command `{command}`")));
+    }
+
+    Ok(())
+  }
+
+  /// Check that every key maps to a known command
+  pub fn validate(&self) -> Result<(), KeymapError> {
+    for (key, command) in &self.0 {
+      if !is_command(command) {
+        let msg = format!("key `{key}`: unknown command `{command}`");
+        return Err(KeymapError(msg));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Load user overrides, one `key = COMMAND` per line. This is a subset of
+  /// TOML: keys and commands may be quoted (`"=" = "ENTER"` or `'"' = "I"`),
+  /// `#` starts a comment and `key = ""` removes the mapping. On error the
+  /// keymap is left unchanged.
+  pub fn load(&mut self, text: &str) -> Result<(), KeymapError> {
+    let mut keymap = self.clone();
+    for (n, line) in text.lines().enumerate() {
+      let err = |msg: &str| KeymapError(format!("line {}: {msg}", n + 1));
+      let Some((key, command)) = parse_line(line).map_err(&err)? else {
+        continue;
+      };
+      keymap.bind(&key, &command).map_err(|e| err(&e.0))?;
+    }
+    *self = keymap;
+
+    Ok(())
+  }
+}
+
+/// Parse `key = value`, return `None` for blank and comment lines
+pub fn parse_line(
+  line: &str,
+) -> Result<Option<(String, String)>, &'static str> {
+  let line = line.trim();
+  if line.is_empty() || line.starts_with('#') {
+    return Ok(None);
+  }
+
+  let (key, rest) = parse_word(line)?;
+  let rest = rest.trim_start();
+  let Some(rest) = rest.strip_prefix('=') else {
+    return Err("expected `=`");
+  };
+  let (value, rest) = parse_word(rest.trim_start())?;
+  let rest = rest.trim_start();
+  if !rest.is_empty() && !rest.starts_with('#') {
+    return Err("unexpected text after value");
+  }
+
+  Ok(Some((key, value)))
+}
+
+// A word is either quoted with `"` or `'` or runs until whitespace or `=`
+fn parse_word(s: &str) -> Result<(String, &str), &'static str> {
+  for quote in ['"', '\''] {
+    if let Some(quoted) = s.strip_prefix(quote) {
+      let end = quoted.find(quote).ok_or("missing closing quote")?;
+      return Ok((quoted[..end].into(), &quoted[end + 1..]));
+    }
+  }
+
+  let end = s.find(|c: char| c.is_whitespace() || c == '=').unwrap_or(s.len());
+  if end == 0 {
+    return Err("missing key or value");
+  }
+
+  Ok((s[..end].into(), &s[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_default_keymap() {
+    let keymap = Keymap::default();
+    assert_eq!(keymap.validate(), Ok(()));
+    assert_eq!(keymap.get("Enter"), Some("ENTER"));
+    assert_eq!(keymap.get("A-y"), Some("XY"));
+    assert_eq!(keymap.get("C-2"), Some("SQR"));
+    assert_eq!(keymap.get("!"), Some("FAC"));
+    assert_eq!(keymap.get("x"), None);
+  }
+
+  #[test]
+  fn test_load() {
+    let mut keymap = Keymap::default();
+    let text = "# my keys\n\n s = SIN\n\"=\" = \"ENTER\" # like HP\n! = \"\"";
+    assert_eq!(keymap.load(text), Ok(()));
+    assert_eq!(keymap.get("s"), Some("SIN"));
+    assert_eq!(keymap.get("="), Some("ENTER"));
+    assert_eq!(keymap.get("!"), None);
+    assert_eq!(keymap.get("A-s"), Some("SIN"));
+  }
+
+  #[test]
+  fn test_load_errors() {
+    let mut keymap = Keymap::default();
+    let err = |s: &str| KeymapError(s.into());
+
+    assert_eq!(
+      keymap.load("s = NOPE"),
+      Err(err("line 1: unknown command `NOPE`"))
+    );
+    assert_eq!(keymap.load("\ns SIN"), Err(err("line 2: expected `=`")));
+    assert_eq!(
+      keymap.load("\"s = SIN"),
+      Err(err("line 1: missing closing quote"))
+    );
+    assert_eq!(
+      keymap.load("s = SIN COS"),
+      Err(err("line 1: unexpected text after value"))
+    );
+
+    // Failed loads leave the keymap unchanged
+    assert_eq!(
+      keymap.load("s = SIN\nt = NOPE"),
+      Err(err("line 2: unknown command `NOPE`"))
+    );
+    assert_eq!(keymap.get("s"), None);
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,170 @@
+use crate::text::wrap;
+use crate::NumDisplay::{self, *};
+
+mod de;
+mod en;
+mod fr;
+
+/// The languages of the help texts
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)] #[rustfmt::skip]
+pub enum Lang { #[default] En, De, Fr }
+
+impl Lang {
+  pub const ALL: [Lang; 3] = [Lang::En, Lang::De, Lang::Fr];
+
+  pub fn code(self) -> &'static str {
+    match self {
+      Lang::En => "en",
+      Lang::De => "de",
+      Lang::Fr => "fr",
+    }
+  }
+
+  pub fn next(self) -> Self {
+    match self {
+      Lang::En => Lang::De,
+      Lang::De => Lang::Fr,
+      Lang::Fr => Lang::En,
+    }
+  }
+
+  /// Language from a code like `de` or `de-CH`, the region is ignored
+  pub fn from_code(code: &str) -> Option<Self> {
+    let code = code.split(['-', '_']).next().unwrap_or_default();
+    Self::ALL.into_iter().find(|lang| lang.code().eq_ignore_ascii_case(code))
+  }
+
+  fn help_catalog(self) -> &'static phf::Map<&'static str, &'static str> {
+    match self {
+      Lang::En => &en::HELP,
+      Lang::De => &de::HELP,
+      Lang::Fr => &fr::HELP,
+    }
+  }
+
+  fn msg_catalog(self) -> &'static phf::Map<&'static str, &'static str> {
+    match self {
+      Lang::En => &en::MESSAGES,
+      Lang::De => &de::MESSAGES,
+      Lang::Fr => &fr::MESSAGES,
+    }
+  }
+
+  /// The message with the parameters `{0}`, `{1}` etc. replaced by `args`.
+  /// Fall back to English and then to the message id.
+  pub fn msg(self, id: &str, args: &[&str]) -> String {
+    let msg = self.msg_catalog().get(id).or_else(|| en::MESSAGES.get(id));
+    let mut msg = msg.copied().unwrap_or(id).to_owned();
+    for (i, arg) in args.iter().enumerate() {
+      msg = msg.replace(&format!("{{{i}}}"), arg);
+    }
+
+    msg
+  }
+
+  /// The human name of the display mode, for example `fixed, 4 decimals`
+  pub fn disp_name(self, disp: NumDisplay) -> String {
+    match disp {
+      Std => self.msg("disp_std", &[]),
+      Raw => self.msg("disp_raw", &[]),
+      Fix(digits) => self.msg("disp_fix", &[&digits.to_string()]),
+      Sci(digits) => self.msg("disp_sci", &[&digits.to_string()]),
+      Eng(digits) => self.msg("disp_eng", &[&(digits + 1).to_string()]),
+      EngSi(digits) => self.msg("disp_eng_si", &[&(digits + 1).to_string()]),
+      Frac => {
+        self.msg("disp_frac", &[&crate::context::max_denom().to_string()])
+      }
+      Full => self.msg("disp_full", &[]),
+      HexU => self.msg("disp_hex_upper", &[]),
+      HexL => self.msg("disp_hex_lower", &[]),
+    }
+  }
+
+  /// The help text for the command, `"ADD"` for the short and `"ADD_long"`
+  /// for the long one, fall back to English and then to the empty string.
+  pub fn help(self, id: &str) -> String {
+    let id = help_id(id);
+    let text = self.help_catalog().get(&id).or_else(|| en::HELP.get(&id));
+    wrap(text.copied().unwrap_or_default())
+  }
+}
+
+/// The digit buttons share the help `DIGIT` and the menu buttons `A` to `F`
+/// without a command of their own the help `MENU`
+fn help_id(id: &str) -> String {
+  let (command, long) = match id.strip_suffix("_long") {
+    Some(command) => (command, "_long"),
+    None => (id, ""),
+  };
+  match command.as_bytes() {
+    [b'0'..=b'9'] => format!("DIGIT{long}"),
+    [b'A'..=b'F'] if !en::HELP.contains_key(command) => format!("MENU{long}"),
+    _ => id.into(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::calc::{ALT_BUTTONS, BASE_BUTTONS, COMMANDS, INV_BUTTONS};
+  use crate::text::{width, WIDTH};
+
+  #[test]
+  fn test_help_complete() {
+    let buttons = [BASE_BUTTONS, ALT_BUTTONS, INV_BUTTONS].concat();
+    let commands = COMMANDS.keys().chain(&buttons);
+    for command in commands.filter(|command| !command.starts_with('_')) {
+      for lang in Lang::ALL {
+        let long = format!("{command}_long");
+        for id in [*command, &long] {
+          let help = lang.help_catalog().get(&help_id(id));
+          assert!(help.is_some_and(|h| !h.is_empty()), "{lang:?} {id}");
+
+          let help = lang.help(id);
+          let too_long = help.lines().find(|l| width(l) > WIDTH);
+          assert_eq!(too_long, None, "{lang:?} {id}");
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn test_messages_complete() {
+    fn params(msg: &str) -> Vec<&str> {
+      let mut params = msg.match_indices('{').map(|(i, _)| &msg[i..i + 3]);
+      let mut params = params.by_ref().collect::<Vec<_>>();
+      params.sort();
+      params
+    }
+
+    for (id, msg) in en::MESSAGES.entries() {
+      for lang in Lang::ALL {
+        let translated = lang.msg_catalog().get(id);
+        assert!(translated.is_some(), "{lang:?} {id}");
+        assert_eq!(params(translated.unwrap()), params(msg), "{lang:?} {id}");
+      }
+    }
+
+    for lang in Lang::ALL {
+      assert_eq!(lang.msg_catalog().len(), en::MESSAGES.len(), "{lang:?}");
+    }
+  }
+
+  #[test]
+  fn test_msg() {
+    assert_eq!(Lang::De.msg("disp_set", &["Standard"]), "Anzeige: Standard");
+    assert_eq!(Lang::Fr.msg("no_such_id", &[]), "no_such_id");
+    assert_eq!(Lang::En.disp_name(Fix(4)), "fixed, 4 decimals");
+    assert_eq!(Lang::De.disp_name(HexU), "hex Grossbuchstaben");
+  }
+
+  #[test]
+  fn test_from_code() {
+    assert_eq!(Lang::from_code("de-CH"), Some(Lang::De));
+    assert_eq!(Lang::from_code("FR"), Some(Lang::Fr));
+    assert_eq!(Lang::from_code("en_US"), Some(Lang::En));
+    assert_eq!(Lang::from_code("it"), None);
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,477 @@
+// German help texts. The `_long` texts are wrapped to the display width
+// unless all their lines already fit (see `text::wrap()`).
+#[rustfmt::skip]
+pub static HELP: phf::Map<&str, &str> = phf::phf_map! {
+  "ENTER" => "Weitere Zahl eingeben",
+  // ============================== <-- this is the max. width of 35 columns.
+  "ENTER_long" => "  Blättern: Taste 0 oder 2
+
+ **  Zum Einstieg: Was ist UPN?   **
+
+UPN ist die Umgekehrte Polnische
+Notation. UPN ist eine einfache und
+effiziente Rechnernotation ohne
+Klammern.
+
+Um 3 und 4 zu addieren:  3 ENTER 4 +
+
+Eine einfache Regel: Zuerst die
+Zahlen, dann die Operation. Mehr
+dazu unter
+
+          flical.ch/help
+
+Viel Spass!
+",
+
+  "ADD" => "Addition: x = y + x",
+  "ADD_long" => "Der Additionsoperator. Addiert x zu y. Beide Zahlen sind \
+    reell, komplex oder rational und werden bei Bedarf umgewandelt. Das \
+    Ergebnis kommt nach x und der Stapel wird nach unten verschoben.",
+
+  "SUB" => "Subtraktion: x = y - x",
+  "SUB_long" => "Der Subtraktionsoperator. Subtrahiert x von y. Beide Zahlen \
+    sind reell, komplex oder rational und werden bei Bedarf umgewandelt. Das \
+    Ergebnis kommt nach x und der Stapel wird nach unten verschoben.",
+
+  "MUL" => "Multiplikation: x = y * x",
+  "MUL_long" => "Der Multiplikationsoperator. Multipliziert y mit x. Beide \
+    Zahlen sind reell, komplex oder rational und werden bei Bedarf \
+    umgewandelt. Das Ergebnis kommt nach x und der Stapel wird nach unten \
+    verschoben.",
+
+  "DIV" => "Division: x = y / x",
+  "DIV_long" => "Der Divisionsoperator. Dividiert y durch x. Beide Zahlen \
+    sind reell, komplex oder rational und werden bei Bedarf umgewandelt. Das \
+    Ergebnis kommt nach x und der Stapel wird nach unten verschoben.",
+
+  "DOT" => "Dezimalpunkt/Bruchstrich",
+  "DOT_long" => "Beginnt die Eingabe, falls nötig, und gibt den Dezimalpunkt \
+    ein. Steht schon ein Dezimalpunkt ohne Ziffern danach da, wird ein Bruch \
+    eingegeben: der Dezimalpunkt wird zum Bruchstrich zwischen Zähler und \
+    Nenner. Beispiel: 2 . . 5 ergibt 2/5. Ein Dezimalpunkt nach Ziffern gibt \
+    eine gemischte Zahl ein: 2 . 3 . 8 ergibt 2 3/8.",
+
+  "I" => "Imaginäre Einheit i",
+  "I_long" => "Gibt die imaginäre Einheit (die Wurzel aus -1) als Trenner \
+    zwischen dem Real- und dem Imaginärteil einer komplexen Zahl ein.",
+
+  "PM" => "Plusminus: Unsicherheit ±",
+  "PM_long" => "Gibt die Unsicherheit einer reellen Zahl ein, zum Beispiel \
+    12.3 ± 0.4 als 12.3 PM 0.4. Arithmetik und Funktionen pflanzen die \
+    Unsicherheit mit den ungünstigsten Grenzen fort: (10 ± 1) · (10 ± 1) ist \
+    100 ± 21.",
+
+  "E" => "Exponent/Hex-Ziffer E",
+  "E_long" => "Gibt den Exponenten einer einfachen Zahl oder des Real- oder \
+    Imaginärteils einer komplexen Zahl ein. Im Hex-Modus gibt E stattdessen \
+    die Hex-Ziffer E ein.",
+
+  "DEL" => "Ziffer löschen oder x leeren",
+  "DEL_long" => "Löscht während der Eingabe das Zeichen vor dem Cursor. \
+    Sonst wird x geleert, das heisst auf 0 gesetzt.",
+
+  "LEFT" => "Cursor nach links",
+  "LEFT_long" => "Bewegt während der Eingabe den Cursor um ein Zeichen nach \
+    links. Ziffern und DEL wirken beim Cursor.",
+
+  "RIGHT" => "Cursor nach rechts",
+  "RIGHT_long" => "Bewegt während der Eingabe den Cursor um ein Zeichen nach \
+    rechts.",
+
+  "STO" => "x in Register speichern",
+  "STO_long" => "Speichert x in einem der sechzehn Register. Nach STO das \
+    Register 0 bis 9 oder A bis F drücken.",
+
+  "RCL" => "Register abrufen",
+  "RCL_long" => "Ruft eines der sechzehn Register nach x ab. Nach RCL das \
+    Register 0 bis 9 oder A bis F drücken. Der Stapel wird nach oben \
+    verschoben.",
+
+  "XY" => "x und y vertauschen",
+  "XY_long" => "Vertauscht die Werte von x und y. Der Rest des Stapels bleibt \
+    unverändert.",
+
+  "XZ" => "x und z vertauschen",
+  "XZ_long" => "Vertauscht die Werte von x und z. Der Rest des Stapels bleibt \
+    unverändert.",
+
+  "R_UP" => "Stapel nach oben rotieren",
+  "R_UP_long" => "Rotiert den Stapel nach oben: x kommt nach y, y nach z, z \
+    nach t und t rundum nach x.",
+
+  "R_DOWN" => "Stapel nach unten rotieren",
+  "R_DOWN_long" => "Rotiert den Stapel nach unten: t kommt nach z, z nach y, \
+    y nach x und x rundum nach t.",
+
+  "DISP" => "Anzeigemodus",
+  "DISP_long" => "Wählt, wie Zahlen angezeigt werden: Std zeigt bis zu 12 \
+    Ziffern, Fix eine feste Anzahl Nachkommastellen, Sci die \
+    wissenschaftliche Notation mit fester Anzahl Nachkommastellen, \
+    nach Sci auch Eng mit Exponenten in Dreierschritten oder SI-Präfixen \
+    wie 4.7k, nach Fix auch Frac mit Reals als nächstem Bruch wie \
+    ≈355/113 (siehe MAX_DENOM), Hex ganze Zahlen hexadezimal und Raw die \
+    interne Darstellung. Show zeigt den aktuellen Anzeigemodus und x in \
+    voller Genauigkeit (siehe SHOW).",
+
+  "SHOW" => "x ganz zeigen",
+  "SHOW_long" => "x in voller Genauigkeit unter dem Stapel zeigen, auch wenn \
+    es zu breit für seine Zeile ist. Mit 0 und 2 blättern, jede andere Taste \
+    fährt fort. DISP F zeigt x auch so.",
+
+  "CONF" => "Tasten konfigurieren",
+  "CONF_long" => "Ändert die Befehle auf den Tasten und der Tastatur. Die \
+    BASE-, ALT- oder INV-Tasten wählen und die zu ändernde Taste drücken, \
+    oder Key wählen und die zu ändernde Taste der Tastatur drücken. Dann die \
+    Taste mit dem neuen Befehl drücken, mit der Modustaste lässt sich ein \
+    Befehl der ALT- oder INV-Tasten wählen. Reset stellt die \
+    Grundeinstellung wieder her und Spr. wechselt die Sprache.",
+
+  "SIN" => "Sinus: x = sin x",
+  "SIN_long" => "Der Sinus von x im Bogenmass oder in Grad (siehe DEG). \
+    Komplexe Zahlen werden unterstützt.",
+
+  "ASIN" => "Arkussinus: x = asin x",
+  "ASIN_long" => "Die Umkehrung des Sinus, das Ergebnis ist im Bogenmass \
+    oder in Grad (siehe DEG). Komplexe Zahlen werden unterstützt, auch x \
+    ausserhalb von -1 und 1.",
+
+  "COS" => "Kosinus: x = cos x",
+  "COS_long" => "Der Kosinus von x im Bogenmass oder in Grad (siehe DEG). \
+    Komplexe Zahlen werden unterstützt.",
+
+  "ACOS" => "Arkuskosinus: x = acos x",
+  "ACOS_long" => "Die Umkehrung des Kosinus, das Ergebnis ist im Bogenmass \
+    oder in Grad (siehe DEG). Komplexe Zahlen werden unterstützt, auch x \
+    ausserhalb von -1 und 1.",
+
+  "TAN" => "Tangens: x = tan x",
+  "TAN_long" => "Der Tangens von x im Bogenmass oder in Grad (siehe DEG). \
+    Komplexe Zahlen werden unterstützt.",
+
+  "ATAN" => "Arkustangens: x = atan x",
+  "ATAN_long" => "Die Umkehrung des Tangens, das Ergebnis ist im Bogenmass \
+    oder in Grad (siehe DEG). Komplexe Zahlen werden unterstützt.",
+
+  "CHS" => "Vorzeichen wechseln: x = -x",
+  "CHS_long" => "Wechselt das Vorzeichen von x. Bei komplexen Zahlen \
+    wechseln Real- und Imaginärteil das Vorzeichen. Während der Eingabe \
+    wechselt das Vorzeichen des Exponenten, falls es einen gibt, sonst das der \
+    Mantisse, wie bei HP-Rechnern.",
+
+  "ABS" => "Betrag: x = |x|",
+  "ABS_long" => "Der Betrag von x. Bei komplexen Zahlen ist das der Abstand \
+    von null.",
+
+  "LD" => "Dekadischer Logarithmus: lg x",
+  "LD_long" => "Der Logarithmus von x zur Basis 10. Negative und komplexe \
+    Zahlen ergeben komplexe Ergebnisse.",
+
+  "LB" => "Binärer Logarithmus: lb x",
+  "LB_long" => "Der Logarithmus von x zur Basis 2. Negative und komplexe \
+    Zahlen ergeben komplexe Ergebnisse.",
+
+  "LOG" => "Logarithmus: x = log_x y",
+  "LOG_long" => "Der Logarithmus von y zur Basis x. Das Ergebnis kommt nach x \
+    und der Stapel wird nach unten verschoben.",
+
+  "POW" => "Potenz: x = y ^ x",
+  "POW_long" => "Potenziert y mit x. Das Ergebnis kommt nach x und der Stapel \
+    wird nach unten verschoben.",
+
+  "ROOT" => "Wurzel: x-te Wurzel aus y",
+  "ROOT_long" => "Die x-te Wurzel aus y, das heisst y hoch 1/x. Das Ergebnis \
+    kommt nach x und der Stapel wird nach unten verschoben.",
+
+  "SQRT" => "Quadratwurzel: x = √x",
+  "SQRT_long" => "Die Quadratwurzel von x. Quadratwurzeln von ganzen Zahlen \
+    und Brüchen bleiben exakt, zum Beispiel ist √8 gleich 2√2 und √(1/2) \
+    gleich √2/2.",
+
+  "SQR" => "Quadrat: x = x²",
+  "SQR_long" => "Das Quadrat von x, das heisst x mal x. Das Quadrat von √2 \
+    ist exakt 2.",
+
+  "PI" => "Pi: x = π",
+  "PI_long" => "Legt π auf den Stapel. Vielfache von π bleiben exakt wie \
+    3π/4, und Sinus, Kosinus und Tangens von Vielfachen von π/6 und π/4 \
+    sind exakt, zum Beispiel ist sin π/2 gleich 1. Sonst ist das Ergebnis \
+    reell.",
+
+  "RECIP" => "Kehrwert: x = 1 / x",
+  "RECIP_long" => "Der Kehrwert von x. Der Kehrwert eines Bruchs ist wieder \
+    ein Bruch.",
+
+  "INT" => "Ganzzahlteil von x",
+  "INT_long" => "Der ganzzahlige Teil von x, das heisst x gegen null \
+    abgeschnitten. Beispiel: der ganzzahlige Teil von -2.7 ist -2. \
+    Komplexe Zahlen: Real- und Imaginärteil einzeln.",
+
+  "FRAC" => "Nachkommateil von x",
+  "FRAC_long" => "Der Nachkommateil von x, das heisst x ohne seinen \
+    ganzzahligen Teil. Beispiel: der Nachkommateil von -2.7 ist -0.7. \
+    Komplexe Zahlen: Real- und Imaginärteil einzeln.",
+
+  "FAC" => "Fakultät: x = x!",
+  "FAC_long" => "Die Fakultät von x, das Produkt aller ganzen Zahlen von 1 \
+    bis x. Nur für nicht negative ganze Zahlen. Das Ergebnis ist exakt, wenn \
+    es passt, sonst ist es reell.",
+
+  "MOD" => "Modulo: x = y mod x",
+  "MOD_long" => "Der Rest von y geteilt durch x, mit dem Vorzeichen von x. \
+    Exakt für ganze Zahlen. y mod 0 ist y. Das Ergebnis kommt nach x und der \
+    Stapel wird nach unten verschoben.",
+
+  "GCD" => "Grösster gemeinsamer Teiler",
+  "GCD_long" => "Der grösste gemeinsame Teiler der ganzen Zahlen y und x. Das \
+    Ergebnis kommt nach x und der Stapel wird nach unten verschoben.",
+
+  "EXACT" => "Exakter Modus ein oder aus",
+  "EXACT_long" => "Schaltet den exakten Modus ein oder aus. Im exakten Modus \
+    sind Brüche nicht auf 6 Stellen beschränkt, zum Beispiel bleibt 1/999999 + \
+    1/1000001 exakt. Brüche zu lang für die Anzeige werden ungefähr mit ≈ \
+    angezeigt. Braucht grosse ganze Zahlen.",
+
+  "DECIMAL" => "Dezimalmodus ein oder aus",
+  "DECIMAL_long" => "Schaltet den Dezimalmodus ein oder aus. Im Dezimalmodus \
+    sind reelle Zahlen Dezimalzahlen mit 28 Stellen, also ist 0.1 + 0.2 genau \
+    0.3, gut für Geld. Reelle Zahlen ausserhalb des Bereichs der Dezimalzahlen \
+    bleiben reell. Nicht in jeder Version verfügbar.",
+
+  "PREC" => "Genauigkeit der reellen Zahlen",
+  "PREC_long" => "Setzt die Genauigkeit der reellen Zahlen auf x signifikante \
+    Stellen, 10 bis 15. Die reellen Zahlen werden nach jeder Rechnung auf die \
+    Genauigkeit gerundet. Die Anzeige zeigt höchstens 12 Stellen.",
+
+  "MAX_DENOM" => "Größter Nenner",
+  "MAX_DENOM_long" => "Den größten Nenner der Brüche, die Reals annähern, auf x \
+    setzen, 2 bis 999999, zum Beispiel 64 für Zoll. Siehe Frac in DISP.",
+
+  "CONVERT" => "Wandelt y in die Einheit von x um",
+  "CONVERT_long" => "Wandelt die Grösse y in die Einheit von x um, der Wert \
+    von x spielt keine Rolle. Zum Beispiel ergeben 100 km/h und 1 mph \
+    62.137 mph. Die Einheiten müssen dieselbe Dimension haben.",
+
+  "UNIT" => "Einheit von x",
+  "UNIT_long" => "Befehle wie UNIT_km/h geben x eine Einheit, oder wandeln x \
+    um, wenn es schon eine Einheit hat. Sie lassen sich in der \
+    Konfiguration auf Tasten legen. Summen werden in die Einheit von y \
+    umgewandelt, Produkte und Quotienten verbinden die Einheiten. SI-, \
+    abgeleitete und imperiale Einheiten sind bekannt, Temperaturen nur in K.",
+
+  "CATALOG" => "Umrechnungskatalog",
+  "CATALOG_long" => "x mit dem Katalog umrechnen: mit A bis F eine Kategorie \
+    wählen (Länge, Masse, Temperatur, Druck, Energie, Datengrösse), dann mit \
+    A bis F die Umrechnung. Das alte x bleibt in LAST x. Temperaturen werden \
+    mit ihren Nullpunkten umgerechnet.",
+
+  "LAST_X" => "LAST x zurückholen",
+  "LAST_X_long" => "x vor der letzten Umrechnung des Katalogs auf den Stapel \
+    legen.",
+
+  "ANGLE" => "Polare Eingabe r∠θ",
+  "ANGLE_long" => "Den Winkel einer komplexen Zahl in Polarform nach ihrem \
+    Betrag eingeben, zum Beispiel 2 ∠ 30 ist 2∠30°, in Grad oder im \
+    Bogenmass wie mit DEG eingestellt.",
+
+  "DEG" => "Grad oder Bogenmass",
+  "DEG_long" => "Den Winkelmodus zwischen Bogenmass und Grad umschalten. Er \
+    gilt für die Winkelfunktionen und die Polarform komplexer Zahlen.",
+
+  "POLAR" => "Polare Anzeige",
+  "POLAR_long" => "Die Anzeige komplexer Zahlen zwischen re i im und der \
+    Polarform r∠θ umschalten, der Winkel im Winkelmodus (siehe DEG).",
+
+  "MIXED" => "Gemischte Zahlen",
+  "MIXED_long" => "Die Anzeige von Brüchen jenseits ±1 zwischen 19/8 und der \
+    gemischten Zahl 2 3/8 umschalten.",
+
+  "FORMAT" => "Zahlenformat",
+  "FORMAT_long" => "Zwischen den Zahlenformaten wechseln: Zifferngruppen \
+    mit ', oder einem Leerzeichen und das Dezimalzeichen . oder ,. Weitere \
+    Formate in der Konfiguration mit group, group_size und decimal_mark.",
+
+  "TO_POLAR" => "Nach polar: x = r, y = θ",
+  "TO_POLAR_long" => "Die komplexe Zahl x in ihren Betrag r in x und ihren \
+    Winkel θ in y zerlegen.",
+
+  "TO_RECT" => "Nach kartesisch: x = r∠θ",
+  "TO_RECT_long" => "Die komplexe Zahl mit dem Betrag x und dem Winkel y, \
+    die Umkehrung von TO_POLAR.",
+
+  "ARG" => "Winkel von x",
+  "ARG_long" => "Der Winkel von x in der komplexen Ebene, π oder 180° für \
+    negative reelle Zahlen.",
+
+  "CONJ" => "Konjugiert komplex",
+  "CONJ_long" => "Die konjugiert komplexe Zahl von x: das Vorzeichen des \
+    Imaginärteils wechselt.",
+
+  "RE" => "Realteil von x",
+  "RE_long" => "Der Realteil der komplexen Zahl x.",
+
+  "IM" => "Imaginärteil von x",
+  "IM_long" => "Der Imaginärteil der komplexen Zahl x, 0 für reelle Zahlen.",
+
+  "ROUND" => "x runden",
+  "ROUND_long" => "Rundet x auf die nächste ganze Zahl, genau in der Mitte \
+    liegende Werte weg von null. \
+    Komplexe Zahlen: Real- und Imaginärteil einzeln.",
+
+  "ALT" => "Zweite und dritte Funktion",
+  "ALT_long" => "Viele Tasten haben drei Funktionen. Eine ist hellgelb \
+    beschriftet, eine rot für die ALT-Funktion und eine violett für die \
+    INV-Funktion. Einmal drücken für ALT und zweimal für INV. Oben rechts \
+    wird eine Anzeige eingeblendet.",
+
+  "INV" => "Dritte Funktion",
+  "INV_long" => "Wechselt von den ALT-Funktionen zu den violett beschrifteten \
+    INV-Funktionen. Nochmals drücken für die Grundfunktionen.",
+
+  "BASE" => "Grundfunktionen",
+  "BASE_long" => "Wechselt zurück zu den hellgelb beschrifteten \
+    Grundfunktionen.",
+
+  "META" => "Nächster Modus",
+  "META_long" => "Wechselt zum nächsten Modus: von den Grundfunktionen zu \
+    ALT, von ALT zu INV und von INV zurück zu den Grundfunktionen.",
+
+  "DIGIT" => "Ziffer eingeben",
+  "DIGIT_long" => "Gibt die Ziffer beim Cursor ein, eine neue Zahl schiebt \
+    den Stapel nach oben. In einer langen Hilfe blättert 0 nach unten und 2 \
+    nach oben.",
+
+  "MENU" => "Menütaste",
+  "MENU_long" => "Wählt in den Menüs von DISP, CONF und CATALOG den Eintrag \
+    über der Taste. In der Hex-Anzeige gibt sie die Hex-Ziffern A bis F ein.",
+
+  "LN" => "Natürlicher Logarithmus: ln x",
+  "LN_long" => "Der Logarithmus von x zur Basis e. Noch nicht verfügbar.",
+
+  "EXP" => "Exponentialfunktion: e ^ x",
+  "EXP_long" => "e hoch x, die Umkehrung von LN. Noch nicht verfügbar.",
+
+  "PERC" => "Prozent: x = y · x / 100",
+  "PERC_long" => "x Prozent von y, y bleibt. Noch nicht verfügbar.",
+
+  "DPERC" => "Prozentuale Änderung: Δ%",
+  "DPERC_long" => "Die Änderung von y zu x in Prozent von y. Noch nicht \
+    verfügbar.",
+
+  "TO_HMS" => "In Stunden, Minuten, Sekunden",
+  "TO_HMS_long" => "Wandelt die Stunden x mit Dezimalstellen in Stunden, \
+    Minuten und Sekunden um. Noch nicht verfügbar.",
+
+  "TO_H" => "In Stunden",
+  "TO_H_long" => "Wandelt die Stunden, Minuten und Sekunden x in Stunden \
+    mit Dezimalstellen um, die Umkehrung von TO_HMS. Noch nicht verfügbar.",
+
+  "H" => "Hyperbelfunktionen",
+  "H_long" => "Das nächste SIN, COS oder TAN und ihre Umkehrungen sind die \
+    Hyperbelfunktionen. Noch nicht verfügbar.",
+
+  "EDATA" => "Daten eingeben: Σ+",
+  "EDATA_long" => "Fügt x und y den Statistikdaten hinzu. Noch nicht \
+    verfügbar.",
+
+  "RAND" => "Zufallszahl",
+  "RAND_long" => "Eine Zufallszahl zwischen 0 und 1. Noch nicht verfügbar.",
+
+  "UNDO" => "Rückgängig",
+  "UNDO_long" => "Macht den letzten Befehl rückgängig. Noch nicht verfügbar.",
+
+  "REDO" => "Wiederholen",
+  "REDO_long" => "Wiederholt den zuletzt rückgängig gemachten Befehl. Noch \
+    nicht verfügbar.",
+
+  "MACRO" => "Makros",
+  "MACRO_long" => "Zeichnet eine Folge von Befehlen auf und spielt sie ab. \
+    Noch nicht verfügbar.",
+};
+
+// German status and error messages, `{0}`, `{1}` etc. are the parameters
+#[rustfmt::skip]
+pub static MESSAGES: phf::Map<&str, &str> = phf::phf_map! {
+  "lang_name" => "Deutsch",
+
+  // ============================== <-- this is the max. width of 35 columns.
+  "disp_menu" =>        " Std   Fix   Sci   Hex   Roh   Zeig",
+  "disp_digit" =>       "Genauigkeit: Ziffer eingeben   Zeig",
+  "disp_fix_menu" =>    "Fix: Ziffer, oder: Frac        Zeig",
+  "disp_sci_menu" =>    "Sci: Ziffer, oder: Eng   SI    Zeig",
+  "disp_hex" =>         "Gross Klein                    Zeig",
+  "show_x" =>           "x in voller Genauigkeit:",
+  "disp_set" =>         "Anzeige: {0}",
+  "disp_is" =>          "Anzeige ist: {0}",
+  "disp_bad_key" =>     "Falsche Taste? Anzeige: {0}",
+  "disp_std" =>         "Standard",
+  "disp_raw" =>         "roh",
+  "disp_fix" =>         "fest, {0} Nachkommastellen",
+  "disp_sci" =>         "wissenschaftlich, {0} Stellen",
+  "disp_eng" =>         "technisch, {0} Stellen",
+  "disp_eng_si" =>      "SI-Präfixe, {0} Stellen",
+  "disp_frac" =>        "Brüche, Nenner ≤ {0}",
+  "disp_full" =>        "volle Genauigkeit",
+  "disp_hex_upper" =>   "hex Grossbuchstaben",
+  "disp_hex_lower" =>   "hex Kleinbuchstaben",
+
+  "conf_menu" =>        " Base  Alt   Inv   Taste Reset Spr.",
+  "conf_button" =>      "{0}-Taste zum Ändern drücken",
+  "conf_command" =>     "{0} ersetzen durch? Tasten: {1}",
+  "conf_key" =>         "Zu ändernde Taste drücken",
+  "conf_key_command" => "Taste {0} wird? Tasten: {1}",
+  "conf_button_set" =>  "Taste ist jetzt {0}",
+  "conf_key_set" =>     "Taste {0} ist jetzt {1}",
+  "conf_reset" =>       "Konfiguration zurückgesetzt",
+  "conf_lang" =>        "Sprache: {0}",
+  "conf_error" =>       "Fehler: {0}",
+  "conf_mode_button" => "Modustaste ist nicht änderbar",
+  "conf_bad_key" =>     "Falsche Taste? Nichts geändert",
+
+  "catalog_menu" =>     " Länge Masse Temp  Druck Energ Byte",
+  "catalog_done" =>     "{0} in {1} umgerechnet",
+  "catalog_bad_key" =>  "Falsche Taste? x unverändert",
+
+  "err_e_fraction" =>   "Fehler: Kein 'e' bei Brüchen",
+  "err_duplicate" =>    "Fehler: '{0}' schon eingegeben",
+  "err_misplaced" =>    "Fehler: Kein '{0}' hier",
+  "err_exp_digits" =>   "Exponent nur -999 bis 999",
+  "err_denominator" =>  "Nenner nur 1 bis 999999",
+  "err_hex_digits" =>   "Hex nur 1 bis 16 Stellen",
+  "err_numerator" =>    "Zähler zu gross",
+  "err_mixed" =>        "Keine gemischte Zahl",
+  "err_fraction" =>     "Schon ein Bruch",
+  "err_fac" =>          "Fakultät nur von ganzen Zahlen >= 0",
+  "err_integers" =>     "Nur ganze Zahlen",
+  "exact_on" =>         "Exakter Modus ein",
+  "exact_off" =>        "Exakter Modus aus",
+  "err_no_exact" =>     "Kein exakter Modus ohne grosse Zahlen",
+  "decimal_on" =>       "Dezimalmodus ein",
+  "decimal_off" =>      "Dezimalmodus aus",
+  "err_no_decimal" =>   "Kein Dezimalmodus verfügbar",
+  "prec_set" =>         "Genauigkeit: {0} Stellen",
+  "err_precision" =>    "Genauigkeit nur 10 bis 15 Stellen",
+  "max_denom_set" =>    "Größter Nenner: {0}",
+  "err_max_denom" =>    "Nenner nur 2 bis 999999",
+  "err_pm" =>           "Fehler: '±' nur nach einer reellen Zahl",
+  "err_unit" =>         "Fehler: Unbekannte Einheit {0}",
+  "err_units" =>        "Fehler: Einheiten {0} und {1} passen nicht",
+  "err_unit_long" =>    "Fehler: Einheit zu lang",
+  "err_unit_exp" =>     "Fehler: Exponent der Einheit über ±9",
+  "err_unit_plain" =>   "Fehler: Keine Einheit erlaubt, {0}",
+  "err_unit_power" =>   "Fehler: Keine solche Potenz von {0}",
+  "err_catalog_unit" => "Fehler: x hat eine Einheit, CONVERT",
+  "err_angle" =>        "Fehler: '∠' nur nach einer reellen Zahl",
+  "err_polar_inf" =>    "Fehler: Keine Polarform von ∞",
+  "err_paste" =>        "Fehler: Keine Zahl bei Spalte {0}",
+  "err_input" =>        "Fehler: Eingabe keine Zahl",
+  "deg_on" =>           "Winkel in Grad",
+  "deg_off" =>          "Winkel im Bogenmass",
+  "polar_on" =>         "Polare Anzeige ein",
+  "polar_off" =>        "Polare Anzeige aus",
+  "mixed_on" =>         "Gemischte Zahlen ein",
+  "mixed_off" =>        "Gemischte Zahlen aus",
+  "format_set" =>       "Zahlenformat {0}",
+};
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
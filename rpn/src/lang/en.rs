@@ -0,0 +1,463 @@
+// English help texts. The `_long` texts are wrapped to the display width
+// unless all their lines already fit (see `text::wrap()`).
+#[rustfmt::skip]
+pub static HELP: phf::Map<&str, &str> = phf::phf_map! {
+  "ENTER" => "Enter another number",
+  // ============================== <-- this is the max. width of 35 columns.
+  "ENTER_long" => "  Scroll down or up: Hit 0 or 2
+
+ **    A Teaser: What is RPN?     **
+
+RPN is Reverse Polish Notation. RPN
+is an easy and efficient calculator
+notation without parentheses.
+
+To add 3 and 4 hit:    3 ENTER 4 +
+
+A simple rule: Numbers first, then
+the operation. To learn more go to
+
+          flical.ch/help
+
+Have fun!
+",
+
+  "ADD" => "Addition: x = y + x",
+  "ADD_long" => "The addition operator. Add number x to y. Both numbers are real, \
+    complex or rational and are coerced as needed. The result is moved to x \
+    and the stack is shifted down.",
+
+  "SUB" => "Subtraction: x = y - x",
+  "SUB_long" => "The subtraction operator. Subtract x from y. Both numbers are \
+    real, complex or rational and are coerced as needed. The result is moved \
+    to x and the stack is shifted down.",
+
+  "MUL" => "Multiplication: x = y * x",
+  "MUL_long" => "The multiplication operator. Multiply y with x. Both numbers \
+    are real, complex or rational and are coerced as needed. The result is \
+    moved to x and the stack is shifted down.",
+
+  "DIV" => "Division: x = y / x",
+  "DIV_long" => "The division operator. Divide y by x. Both numbers are real, \
+    complex or rational and are coerced as needed. The result is moved to x \
+    and the stack is shifted down.",
+
+  "DOT" => "Decimal point/quotient slash",
+  "DOT_long" => "Enter entry mode if not already and enter the decimal point. \
+    If there's already a decimal point without digits after it, a quotient \
+    will be entered: the decimal point becomes the slash separating the \
+    numerator and the denominator. Example: 2 . . 5 gives 2/5. A decimal \
+    point after digits enters a mixed number: 2 . 3 . 8 gives 2 3/8.",
+
+  "I" => "Imaginary unit i",
+  "I_long" => "Enter the imaginary unit (the square root of -1) as a separator \
+    between the real and the imaginary part of a complex number.",
+
+  "PM" => "Plus-minus: uncertainty ±",
+  "PM_long" => "Enter the uncertainty of a real, for example 12.3 ± 0.4 as \
+    12.3 PM 0.4. Arithmetic and functions propagate the uncertainty with \
+    worst-case bounds: (10 ± 1) · (10 ± 1) is 100 ± 21.",
+
+  "E" => "Exponent/Hex digit E",
+  "E_long" => "Enter the exponent of a simple number or of the real or \
+    imaginary part of a complex number. In hex mode E enters the hex digit E \
+    instead.",
+
+  "DEL" => "Delete digit or clear x",
+  "DEL_long" => "While entering a number delete the character before the \
+    cursor. Else clear x, this means set x to 0.",
+
+  "LEFT" => "Cursor left",
+  "LEFT_long" => "While entering a number move the cursor one character to the \
+    left. Digits and DEL work at the cursor.",
+
+  "RIGHT" => "Cursor right",
+  "RIGHT_long" => "While entering a number move the cursor one character to \
+    the right.",
+
+  "STO" => "Store x in a register",
+  "STO_long" => "Store x in one of the sixteen registers. Press the register \
+    0 to 9 or A to F after STO.",
+
+  "RCL" => "Recall a register",
+  "RCL_long" => "Recall one of the sixteen registers to x. Press the register \
+    0 to 9 or A to F after RCL. The stack is shifted up.",
+
+  "XY" => "Exchange x and y",
+  "XY_long" => "Exchange the values of x and y. The rest of the stack is not \
+    changed.",
+
+  "XZ" => "Exchange x and z",
+  "XZ_long" => "Exchange the values of x and z. The rest of the stack is not \
+    changed.",
+
+  "R_UP" => "Roll the stack up",
+  "R_UP_long" => "Roll the stack up: x moves to y, y to z, z to t and t comes \
+    around to x.",
+
+  "R_DOWN" => "Roll the stack down",
+  "R_DOWN_long" => "Roll the stack down: t moves to z, z to y, y to x and x \
+    comes around to t.",
+
+  "DISP" => "Display mode",
+  "DISP_long" => "Choose how numbers are displayed: Std shows up to 12 \
+    digits, Fix a fixed count of decimals, Sci the scientific notation with \
+    a fixed count of decimals, after Sci also Eng with exponents in \
+    multiples of three or SI prefixes like 4.7k, after Fix also Frac with \
+    reals as the nearest quotient like ≈355/113 (see MAX_DENOM), Hex \
+    integers in hexadecimal and Raw the internal representation. Show tells \
+    the current display mode and shows x in full precision (see SHOW).",
+
+  "SHOW" => "Show x in full",
+  "SHOW_long" => "Show x in full precision below the stack, also when it is \
+    too wide for its line. Scroll with 0 and 2, any other key continues. DISP \
+    F shows x like this too.",
+
+  "CONF" => "Configure buttons and keys",
+  "CONF_long" => "Change the commands on the buttons and keys. Choose the \
+    BASE, ALT or INV buttons and press the button to change, or choose Key \
+    and press the key to change. Then press the button with the new command, \
+    use the mode button to pick a command from the ALT or INV buttons. Reset \
+    restores the default configuration and Lang switches the language.",
+
+  "SIN" => "Sine: x = sin x",
+  "SIN_long" => "The sine of x in radians or degrees (see DEG). Complex \
+    numbers are supported.",
+
+  "ASIN" => "Arc sine: x = asin x",
+  "ASIN_long" => "The inverse of the sine, the result is in radians or \
+    degrees (see DEG). Complex numbers are supported, also x beyond -1 and 1.",
+
+  "COS" => "Cosine: x = cos x",
+  "COS_long" => "The cosine of x in radians or degrees (see DEG). Complex \
+    numbers are supported.",
+
+  "ACOS" => "Arc cosine: x = acos x",
+  "ACOS_long" => "The inverse of the cosine, the result is in radians or \
+    degrees (see DEG). Complex numbers are supported, also x beyond -1 and 1.",
+
+  "TAN" => "Tangent: x = tan x",
+  "TAN_long" => "The tangent of x in radians or degrees (see DEG). Complex \
+    numbers are supported.",
+
+  "ATAN" => "Arc tangent: x = atan x",
+  "ATAN_long" => "The inverse of the tangent, the result is in radians or \
+    degrees (see DEG). Complex numbers are supported.",
+
+  "CHS" => "Change sign: x = -x",
+  "CHS_long" => "Change the sign of x. For complex numbers both the real and \
+    the imaginary part change their sign. While entering a number change the \
+    sign of the exponent if there is one, else of the mantissa, like on HP \
+    calculators.",
+
+  "ABS" => "Absolute value: x = |x|",
+  "ABS_long" => "The absolute value of x. For complex numbers this is the \
+    modulus, the distance from zero.",
+
+  "LD" => "Decimal logarithm: x = lg x",
+  "LD_long" => "The logarithm of x to the base 10. Negative and complex \
+    numbers give complex results.",
+
+  "LB" => "Binary logarithm: x = lb x",
+  "LB_long" => "The logarithm of x to the base 2. Negative and complex \
+    numbers give complex results.",
+
+  "LOG" => "Logarithm: x = log_x y",
+  "LOG_long" => "The logarithm of y to the base x. The result is moved to x \
+    and the stack is shifted down.",
+
+  "POW" => "Power: x = y ^ x",
+  "POW_long" => "Raise y to the power of x. The result is moved to x and the \
+    stack is shifted down.",
+
+  "ROOT" => "Root: x = x-th root of y",
+  "ROOT_long" => "The x-th root of y, this means y raised to the power of \
+    1/x. The result is moved to x and the stack is shifted down.",
+
+  "SQRT" => "Square root: x = √x",
+  "SQRT_long" => "The square root of x. Square roots of integers and \
+    quotients stay exact, for example √8 is 2√2 and √(1/2) is √2/2.",
+
+  "SQR" => "Square: x = x²",
+  "SQR_long" => "The square of x, this means x times x. The square of √2 is \
+    exactly 2.",
+
+  "PI" => "Pi: x = π",
+  "PI_long" => "Push π on the stack. Multiples of π stay exact like 3π/4, and \
+    sine, cosine and tangent of multiples of π/6 and π/4 are exact, for \
+    example sin π/2 is 1. Else the result is a real.",
+
+  "RECIP" => "Reciprocal: x = 1 / x",
+  "RECIP_long" => "The reciprocal of x. The reciprocal of a quotient is again \
+    a quotient.",
+
+  "INT" => "Integer part of x",
+  "INT_long" => "The integer part of x, this means x truncated towards zero. \
+    Example: the integer part of -2.7 is -2. \
+    Complex numbers: the real and the imaginary part separately.",
+
+  "FRAC" => "Fractional part of x",
+  "FRAC_long" => "The fractional part of x, this means x without its integer \
+    part. Example: the fractional part of -2.7 is -0.7. \
+    Complex numbers: the real and the imaginary part separately.",
+
+  "FAC" => "Factorial: x = x!",
+  "FAC_long" => "The factorial of x, the product of all integers from 1 to x. \
+    Only for integers not negative. The result is exact if it fits, else it's \
+    a real.",
+
+  "MOD" => "Modulo: x = y mod x",
+  "MOD_long" => "The remainder of y divided by x, with the sign of x. Exact for \
+    integers. y mod 0 is y. The result is moved to x and the stack is shifted \
+    down.",
+
+  "GCD" => "Greatest common divisor",
+  "GCD_long" => "The greatest common divisor of the integers y and x. The \
+    result is moved to x and the stack is shifted down.",
+
+  "EXACT" => "Exact mode on or off",
+  "EXACT_long" => "Switch the exact mode on or off. In the exact mode \
+    quotients are not limited to 6 digits, for example 1/999999 + 1/1000001 \
+    stays exact. Quotients too long for the display are shown approximately \
+    with ≈. Needs big integers.",
+
+  "DECIMAL" => "Decimal mode on or off",
+  "DECIMAL_long" => "Switch the decimal mode on or off. In the decimal mode \
+    reals are decimals with 28 digits, so 0.1 + 0.2 is exactly 0.3, good for \
+    money. Reals beyond the range of the decimals stay reals. Not available in \
+    every build.",
+
+  "PREC" => "Precision of the reals",
+  "PREC_long" => "Set the precision of the reals to x significant digits, 10 \
+    to 15. The reals are rounded to the precision after each calculation. The \
+    display shows at most 12 digits.",
+
+  "MAX_DENOM" => "Largest denominator",
+  "MAX_DENOM_long" => "Set the largest denominator of the quotients approximating \
+    reals to x, 2 to 999999, for example 64 for inches. See Frac in DISP.",
+
+  "CONVERT" => "Convert y to the unit of x",
+  "CONVERT_long" => "Convert the quantity y to the unit of x, the value of x \
+    doesn't matter. For example 100 km/h and 1 mph give 62.137 mph. \
+    The units must have the same dimension.",
+
+  "UNIT" => "Unit of x",
+  "UNIT_long" => "Commands like UNIT_km/h attach a unit to x, or convert x \
+    if it already has a unit. Put them on keys or buttons in the \
+    configuration. Sums convert to the unit of y, products and quotients \
+    combine the units. SI, derived and imperial units are known, \
+    temperatures in K only.",
+
+  "CATALOG" => "Conversion catalogue",
+  "CATALOG_long" => "Convert x with the catalogue: choose a category with A \
+    to F (length, mass, temperature, pressure, energy, data size), then the \
+    conversion with A to F. The old x is kept in LAST x. Temperatures are \
+    converted with their zero points.",
+
+  "LAST_X" => "Recall LAST x",
+  "LAST_X_long" => "Push x before the last conversion of the catalogue onto \
+    the stack.",
+
+  "ANGLE" => "Polar entry r∠θ",
+  "ANGLE_long" => "Enter the angle of a complex number in the polar form \
+    after its magnitude, for example 2 ∠ 30 is 2∠30°, in degrees or radians \
+    as set by DEG.",
+
+  "DEG" => "Degrees or radians",
+  "DEG_long" => "Switch the angle mode between radians and degrees. The mode \
+    applies to the trigonometric functions and to the polar form of complex \
+    numbers.",
+
+  "POLAR" => "Polar display",
+  "POLAR_long" => "Switch the display of complex numbers between re i im and \
+    the polar form r∠θ, the angle in the angle mode (see DEG).",
+
+  "MIXED" => "Mixed numbers",
+  "MIXED_long" => "Switch the display of quotients beyond ±1 between 19/8 and \
+    the mixed number 2 3/8.",
+
+  "FORMAT" => "Number format",
+  "FORMAT_long" => "Switch through the number formats: digit groups with \
+    ', or a space and the decimal mark . or ,. More formats in the \
+    configuration with group, group_size and decimal_mark.",
+
+  "TO_POLAR" => "To polar: x = r, y = θ",
+  "TO_POLAR_long" => "Split the complex number x into its magnitude r in x \
+    and its angle θ in y.",
+
+  "TO_RECT" => "To rectangular: x = r∠θ",
+  "TO_RECT_long" => "The complex number with the magnitude x and the angle \
+    y, the inverse of TO_POLAR.",
+
+  "ARG" => "Angle of x",
+  "ARG_long" => "The angle of x in the complex plane, π or 180° for negative \
+    reals.",
+
+  "CONJ" => "Complex conjugate",
+  "CONJ_long" => "The complex conjugate of x: the sign of the imaginary part \
+    changes.",
+
+  "RE" => "Real part of x",
+  "RE_long" => "The real part of the complex number x.",
+
+  "IM" => "Imaginary part of x",
+  "IM_long" => "The imaginary part of the complex number x, 0 for reals.",
+
+  "ROUND" => "Round x",
+  "ROUND_long" => "Round x to the nearest integer, halfway cases away from \
+    zero. \
+    Complex numbers: the real and the imaginary part separately.",
+
+  "ALT" => "Second and third function",
+  "ALT_long" => "Many buttons have three functions. One is printed in bright \
+    yellow, one in red for the ALT function and one in purple for the INV \
+    function. Press once for ALT and twice for INV. An indicator is shown in \
+    the top right corner.",
+
+  "INV" => "Third function",
+  "INV_long" => "Switch from the ALT functions to the INV functions printed \
+    in purple. Press again to go back to the base functions.",
+
+  "BASE" => "Base functions",
+  "BASE_long" => "Switch back to the base functions printed in bright \
+    yellow.",
+
+  "META" => "Next mode",
+  "META_long" => "Switch to the next mode: from the base functions to ALT, \
+    from ALT to INV and from INV back to the base functions.",
+
+  "DIGIT" => "Enter a digit",
+  "DIGIT_long" => "Enter the digit at the cursor, a new number pushes the \
+    stack up. In a long help 0 scrolls down and 2 scrolls up.",
+
+  "MENU" => "Menu key",
+  "MENU_long" => "Choose the item above the key in the menus of DISP, CONF \
+    and CATALOG. In the hex display enter the hex digits A to F.",
+
+  "LN" => "Natural logarithm: x = ln x",
+  "LN_long" => "The logarithm of x to the base e. Not available yet.",
+
+  "EXP" => "Exponential: x = e ^ x",
+  "EXP_long" => "e to the power of x, the inverse of LN. Not available yet.",
+
+  "PERC" => "Percent: x = y · x / 100",
+  "PERC_long" => "x percent of y, y stays. Not available yet.",
+
+  "DPERC" => "Percent change: Δ%",
+  "DPERC_long" => "The change from y to x in percent of y. Not available \
+    yet.",
+
+  "TO_HMS" => "To hours, minutes, seconds",
+  "TO_HMS_long" => "Convert the hours x with decimals to hours, minutes and \
+    seconds. Not available yet.",
+
+  "TO_H" => "To hours",
+  "TO_H_long" => "Convert the hours, minutes and seconds x to hours with \
+    decimals, the inverse of TO_HMS. Not available yet.",
+
+  "H" => "Hyperbolic functions",
+  "H_long" => "The next SIN, COS or TAN and their inverses are the \
+    hyperbolic functions. Not available yet.",
+
+  "EDATA" => "Enter data: Σ+",
+  "EDATA_long" => "Add x and y to the statistics data. Not available yet.",
+
+  "RAND" => "Random number",
+  "RAND_long" => "A random number between 0 and 1. Not available yet.",
+
+  "UNDO" => "Undo",
+  "UNDO_long" => "Undo the last command. Not available yet.",
+
+  "REDO" => "Redo",
+  "REDO_long" => "Redo the last undone command. Not available yet.",
+
+  "MACRO" => "Macros",
+  "MACRO_long" => "Record and play a sequence of commands. Not available yet.",
+};
+
+// English status and error messages, `{0}`, `{1}` etc. are the parameters
+#[rustfmt::skip]
+pub static MESSAGES: phf::Map<&str, &str> = phf::phf_map! {
+  "lang_name" => "English",
+
+  // ============================== <-- this is the max. width of 35 columns.
+  "disp_menu" =>        " Std   Fix   Sci   Hex   Raw   Show",
+  "disp_digit" =>       "Enter digit for precision      Show",
+  "disp_fix_menu" =>    "Digit for Fix, or: Frac        Show",
+  "disp_sci_menu" =>    "Digit for Sci, or: Eng   SI    Show",
+  "disp_hex" =>         "Upper Lower                    Show",
+  "show_x" =>           "x in full precision:",
+  "disp_set" =>         "Display set to: {0}",
+  "disp_is" =>          "Display is: {0}",
+  "disp_bad_key" =>     "Bad key? Display is: {0}",
+  "disp_std" =>         "standard",
+  "disp_raw" =>         "raw",
+  "disp_fix" =>         "fixed, {0} decimals",
+  "disp_sci" =>         "scientific, {0} decimals",
+  "disp_eng" =>         "engineering, {0} digits",
+  "disp_eng_si" =>      "SI prefixes, {0} digits",
+  "disp_frac" =>        "fractions, denominator ≤ {0}",
+  "disp_full" =>        "full precision",
+  "disp_hex_upper" =>   "hex uppercase",
+  "disp_hex_lower" =>   "hex lowercase",
+
+  "conf_menu" =>        " Base  Alt   Inv   Key   Reset Lang",
+  "conf_button" =>      "Press {0} button to change",
+  "conf_command" =>     "Replace {0} by? Buttons: {1}",
+  "conf_key" =>         "Press key to change",
+  "conf_key_command" => "Key {0} to? Buttons: {1}",
+  "conf_button_set" =>  "Button set to {0}",
+  "conf_key_set" =>     "Key {0} set to {1}",
+  "conf_reset" =>       "Configuration reset",
+  "conf_lang" =>        "Language: {0}",
+  "conf_error" =>       "Error: {0}",
+  "conf_mode_button" => "Can't change the mode button",
+  "conf_bad_key" =>     "Bad key? Configuration unchanged",
+
+  "catalog_menu" =>     " Len   Mass  Temp  Press Enrgy Data",
+  "catalog_done" =>     "Converted {0} to {1}",
+  "catalog_bad_key" =>  "Bad key? x unchanged",
+
+  "err_e_fraction" =>   "Error: No 'e' for fractions",
+  "err_duplicate" =>    "Error: Duplicate '{0}'",
+  "err_misplaced" =>    "Error: No '{0}' here",
+  "err_exp_digits" =>   "Exponent -999 to 999 only",
+  "err_denominator" =>  "Denominator 1 to 999999 only",
+  "err_hex_digits" =>   "Hex 1 to 16 digits only",
+  "err_numerator" =>    "Numerator too large",
+  "err_mixed" =>        "Not a mixed number",
+  "err_fraction" =>     "Already a fraction",
+  "err_fac" =>          "Factorial of integers >= 0 only",
+  "err_integers" =>     "Integers only",
+  "exact_on" =>         "Exact mode on",
+  "exact_off" =>        "Exact mode off",
+  "err_no_exact" =>     "No exact mode without big integers",
+  "decimal_on" =>       "Decimal mode on",
+  "decimal_off" =>      "Decimal mode off",
+  "err_no_decimal" =>   "No decimal mode available",
+  "prec_set" =>         "Precision: {0} digits",
+  "err_precision" =>    "Precision 10 to 15 digits only",
+  "max_denom_set" =>    "Largest denominator: {0}",
+  "err_max_denom" =>    "Denominator 2 to 999999 only",
+  "err_pm" =>           "Error: '±' only after a real",
+  "err_unit" =>         "Error: Unknown unit {0}",
+  "err_units" =>        "Error: Units {0} and {1} don't match",
+  "err_unit_long" =>    "Error: Unit too long",
+  "err_unit_exp" =>     "Error: Unit exponent beyond ±9",
+  "err_unit_plain" =>   "Error: No unit allowed, not {0}",
+  "err_unit_power" =>   "Error: No such power of {0}",
+  "err_catalog_unit" => "Error: x has a unit, use CONVERT",
+  "err_angle" =>        "Error: '∠' only after a real",
+  "err_polar_inf" =>    "Error: No polar form of ∞",
+  "err_paste" =>        "Error: Not a number at column {0}",
+  "err_input" =>        "Error: Input not a number",
+  "deg_on" =>           "Angles in degrees",
+  "deg_off" =>          "Angles in radians",
+  "polar_on" =>         "Polar display on",
+  "polar_off" =>        "Polar display off",
+  "mixed_on" =>         "Mixed numbers on",
+  "mixed_off" =>        "Mixed numbers off",
+  "format_set" =>       "Number format {0}",
+};
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,474 @@
+// French help texts. The `_long` texts are wrapped to the display width
+// unless all their lines already fit (see `text::wrap()`).
+#[rustfmt::skip]
+pub static HELP: phf::Map<&str, &str> = phf::phf_map! {
+  "ENTER" => "Entrer un autre nombre",
+  // ============================== <-- this is the max. width of 35 columns.
+  "ENTER_long" => "  Défiler: touche 0 ou 2
+
+ ** Pour commencer: c'est quoi RPN?**
+
+RPN est la notation polonaise
+inverse, une notation simple et
+efficace sans parenthèses.
+
+Pour additionner 3 et 4:  3 ENTER 4 +
+
+Une règle simple: d'abord les
+nombres, puis l'opération. Pour en
+savoir plus:
+
+          flical.ch/help
+
+Amusez-vous bien!
+",
+
+  "ADD" => "Addition: x = y + x",
+  "ADD_long" => "L'opérateur d'addition. Ajoute x à y. Les deux nombres sont \
+    réels, complexes ou rationnels et sont convertis si nécessaire. Le \
+    résultat va dans x et la pile descend.",
+
+  "SUB" => "Soustraction: x = y - x",
+  "SUB_long" => "L'opérateur de soustraction. Soustrait x de y. Les deux \
+    nombres sont réels, complexes ou rationnels et sont convertis si \
+    nécessaire. Le résultat va dans x et la pile descend.",
+
+  "MUL" => "Multiplication: x = y * x",
+  "MUL_long" => "L'opérateur de multiplication. Multiplie y par x. Les deux \
+    nombres sont réels, complexes ou rationnels et sont convertis si \
+    nécessaire. Le résultat va dans x et la pile descend.",
+
+  "DIV" => "Division: x = y / x",
+  "DIV_long" => "L'opérateur de division. Divise y par x. Les deux nombres \
+    sont réels, complexes ou rationnels et sont convertis si nécessaire. Le \
+    résultat va dans x et la pile descend.",
+
+  "DOT" => "Point décimal/barre de fraction",
+  "DOT_long" => "Commence la saisie si nécessaire et entre le point décimal. \
+    S'il y a déjà un point décimal sans chiffres après, une fraction est \
+    saisie: le point décimal devient la barre entre le numérateur et le \
+    dénominateur. Exemple: 2 . . 5 donne 2/5. Un point décimal après des \
+    chiffres entre un nombre fractionnaire: 2 . 3 . 8 donne 2 3/8.",
+
+  "I" => "Unité imaginaire i",
+  "I_long" => "Entre l'unité imaginaire (la racine carrée de -1) comme \
+    séparateur entre la partie réelle et la partie imaginaire d'un nombre \
+    complexe.",
+
+  "PM" => "Plus-moins: incertitude ±",
+  "PM_long" => "Entre l'incertitude d'un réel, par exemple 12.3 ± 0.4 comme \
+    12.3 PM 0.4. L'arithmétique et les fonctions propagent l'incertitude \
+    avec les bornes les plus défavorables: (10 ± 1) · (10 ± 1) est 100 ± 21.",
+
+  "E" => "Exposant/chiffre hexa E",
+  "E_long" => "Entre l'exposant d'un nombre simple ou de la partie réelle ou \
+    imaginaire d'un nombre complexe. En mode hexa, E entre le chiffre \
+    hexadécimal E.",
+
+  "DEL" => "Effacer un chiffre ou x",
+  "DEL_long" => "Pendant la saisie, efface le caractère avant le curseur. \
+    Sinon efface x, c'est-à-dire met x à 0.",
+
+  "LEFT" => "Curseur à gauche",
+  "LEFT_long" => "Pendant la saisie, déplace le curseur d'un caractère vers la \
+    gauche. Les chiffres et DEL agissent au curseur.",
+
+  "RIGHT" => "Curseur à droite",
+  "RIGHT_long" => "Pendant la saisie, déplace le curseur d'un caractère vers \
+    la droite.",
+
+  "STO" => "Stocker x dans un registre",
+  "STO_long" => "Stocke x dans l'un des seize registres. Après STO, appuyer \
+    sur le registre 0 à 9 ou A à F.",
+
+  "RCL" => "Rappeler un registre",
+  "RCL_long" => "Rappelle l'un des seize registres dans x. Après RCL, appuyer \
+    sur le registre 0 à 9 ou A à F. La pile monte.",
+
+  "XY" => "Échanger x et y",
+  "XY_long" => "Échange les valeurs de x et y. Le reste de la pile ne change \
+    pas.",
+
+  "XZ" => "Échanger x et z",
+  "XZ_long" => "Échange les valeurs de x et z. Le reste de la pile ne change \
+    pas.",
+
+  "R_UP" => "Rotation de la pile en haut",
+  "R_UP_long" => "Fait tourner la pile vers le haut: x va dans y, y dans z, z \
+    dans t et t revient dans x.",
+
+  "R_DOWN" => "Rotation de la pile en bas",
+  "R_DOWN_long" => "Fait tourner la pile vers le bas: t va dans z, z dans y, \
+    y dans x et x revient dans t.",
+
+  "DISP" => "Mode d'affichage",
+  "DISP_long" => "Choisit l'affichage des nombres: Std montre jusqu'à 12 \
+    chiffres, Fix un nombre fixe de décimales, Sci la notation scientifique \
+    avec un nombre fixe de décimales, après Sci aussi Eng avec des \
+    exposants multiples de trois ou des préfixes SI comme 4.7k, après Fix \
+    aussi Frac avec les réels en fraction la plus proche comme ≈355/113 (voir \
+    MAX_DENOM), Hex les entiers en hexadécimal et Raw la représentation \
+    interne. Show indique le mode d'affichage actuel et montre x en \
+    précision complète (voir SHOW).",
+
+  "SHOW" => "Montrer x en entier",
+  "SHOW_long" => "Montrer x en précision complète sous la pile, aussi s'il \
+    est trop large pour sa ligne. Défiler avec 0 et 2, toute autre touche \
+    continue. DISP F montre aussi x ainsi.",
+
+  "CONF" => "Configurer touches et clavier",
+  "CONF_long" => "Change les commandes des touches et du clavier. Choisir \
+    les touches BASE, ALT ou INV et appuyer sur la touche à changer, ou \
+    choisir Key et appuyer sur la touche du clavier à changer. Puis appuyer \
+    sur la touche de la nouvelle commande, la touche de mode permet de \
+    choisir une commande des touches ALT ou INV. Reset rétablit la \
+    configuration par défaut et Lang change la langue.",
+
+  "SIN" => "Sinus: x = sin x",
+  "SIN_long" => "Le sinus de x en radians ou en degrés (voir DEG). Les \
+    nombres complexes sont supportés.",
+
+  "ASIN" => "Arc sinus: x = asin x",
+  "ASIN_long" => "L'inverse du sinus, le résultat est en radians ou en \
+    degrés (voir DEG). Les nombres complexes sont supportés, aussi x hors de \
+    -1 et 1.",
+
+  "COS" => "Cosinus: x = cos x",
+  "COS_long" => "Le cosinus de x en radians ou en degrés (voir DEG). Les \
+    nombres complexes sont supportés.",
+
+  "ACOS" => "Arc cosinus: x = acos x",
+  "ACOS_long" => "L'inverse du cosinus, le résultat est en radians ou en \
+    degrés (voir DEG). Les nombres complexes sont supportés, aussi x hors de \
+    -1 et 1.",
+
+  "TAN" => "Tangente: x = tan x",
+  "TAN_long" => "La tangente de x en radians ou en degrés (voir DEG). Les \
+    nombres complexes sont supportés.",
+
+  "ATAN" => "Arc tangente: x = atan x",
+  "ATAN_long" => "L'inverse de la tangente, le résultat est en radians ou \
+    en degrés (voir DEG). Les nombres complexes sont supportés.",
+
+  "CHS" => "Changer le signe: x = -x",
+  "CHS_long" => "Change le signe de x. Pour les nombres complexes, la partie \
+    réelle et la partie imaginaire changent de signe. Pendant la saisie, \
+    change le signe de l'exposant s'il y en a un, sinon celui de la mantisse, \
+    comme sur les calculatrices HP.",
+
+  "ABS" => "Valeur absolue: x = |x|",
+  "ABS_long" => "La valeur absolue de x. Pour les nombres complexes c'est le \
+    module, la distance à zéro.",
+
+  "LD" => "Logarithme décimal: lg x",
+  "LD_long" => "Le logarithme de x en base 10. Les nombres négatifs et \
+    complexes donnent des résultats complexes.",
+
+  "LB" => "Logarithme binaire: lb x",
+  "LB_long" => "Le logarithme de x en base 2. Les nombres négatifs et \
+    complexes donnent des résultats complexes.",
+
+  "LOG" => "Logarithme: x = log_x y",
+  "LOG_long" => "Le logarithme de y en base x. Le résultat va dans x et la \
+    pile descend.",
+
+  "POW" => "Puissance: x = y ^ x",
+  "POW_long" => "Élève y à la puissance x. Le résultat va dans x et la pile \
+    descend.",
+
+  "ROOT" => "Racine: racine x-ième de y",
+  "ROOT_long" => "La racine x-ième de y, c'est-à-dire y à la puissance 1/x. \
+    Le résultat va dans x et la pile descend.",
+
+  "SQRT" => "Racine carrée: x = √x",
+  "SQRT_long" => "La racine carrée de x. Les racines carrées des entiers et \
+    des fractions restent exactes, par exemple √8 est 2√2 et √(1/2) est \
+    √2/2.",
+
+  "SQR" => "Carré: x = x²",
+  "SQR_long" => "Le carré de x, c'est-à-dire x fois x. Le carré de √2 est \
+    exactement 2.",
+
+  "PI" => "Pi: x = π",
+  "PI_long" => "Met π sur la pile. Les multiples de π restent exacts comme \
+    3π/4, et sinus, cosinus et tangente des multiples de π/6 et π/4 sont \
+    exacts, par exemple sin π/2 est 1. Sinon le résultat est réel.",
+
+  "RECIP" => "Inverse: x = 1 / x",
+  "RECIP_long" => "L'inverse de x. L'inverse d'une fraction est encore une \
+    fraction.",
+
+  "INT" => "Partie entière de x",
+  "INT_long" => "La partie entière de x, c'est-à-dire x tronqué vers zéro. \
+    Exemple: la partie entière de -2.7 est -2. \
+    Nombres complexes: parties réelle et imaginaire séparément.",
+
+  "FRAC" => "Partie fractionnaire de x",
+  "FRAC_long" => "La partie fractionnaire de x, c'est-à-dire x sans sa partie \
+    entière. Exemple: la partie fractionnaire de -2.7 est -0.7. \
+    Nombres complexes: parties réelle et imaginaire séparément.",
+
+  "FAC" => "Factorielle: x = x!",
+  "FAC_long" => "La factorielle de x, le produit de tous les entiers de 1 à x. \
+    Seulement pour les entiers non négatifs. Le résultat est exact s'il tient \
+    dans l'affichage, sinon c'est un réel.",
+
+  "MOD" => "Modulo: x = y mod x",
+  "MOD_long" => "Le reste de y divisé par x, avec le signe de x. Exact pour \
+    les entiers. y mod 0 est y. Le résultat va dans x et la pile descend.",
+
+  "GCD" => "Plus grand commun diviseur",
+  "GCD_long" => "Le plus grand commun diviseur des entiers y et x. Le \
+    résultat va dans x et la pile descend.",
+
+  "EXACT" => "Mode exact oui ou non",
+  "EXACT_long" => "Active ou désactive le mode exact. En mode exact les \
+    fractions ne sont pas limitées à 6 chiffres, par exemple 1/999999 + \
+    1/1000001 reste exact. Les fractions trop longues pour l'affichage sont \
+    montrées environ avec ≈. Nécessite les grands entiers.",
+
+  "DECIMAL" => "Mode décimal oui ou non",
+  "DECIMAL_long" => "Active ou désactive le mode décimal. En mode décimal les \
+    réels sont des décimaux à 28 chiffres, donc 0.1 + 0.2 vaut exactement 0.3, \
+    bien pour l'argent. Les réels hors de la plage des décimaux restent réels. \
+    Pas disponible dans toutes les versions.",
+
+  "PREC" => "Précision des réels",
+  "PREC_long" => "Règle la précision des réels à x chiffres significatifs, de \
+    10 à 15. Les réels sont arrondis à la précision après chaque calcul. \
+    L'affichage montre au plus 12 chiffres.",
+
+  "MAX_DENOM" => "Plus grand dénominateur",
+  "MAX_DENOM_long" => "Fixer le plus grand dénominateur des fractions qui \
+    approchent les réels à x, de 2 à 999999, par exemple 64 pour les pouces. \
+    Voir Frac dans DISP.",
+
+  "CONVERT" => "Convertit y dans l'unité de x",
+  "CONVERT_long" => "Convertit la grandeur y dans l'unité de x, la valeur de \
+    x ne compte pas. Par exemple 100 km/h et 1 mph donnent 62.137 \
+    mph. Les unités doivent avoir la même dimension.",
+
+  "UNIT" => "Unité de x",
+  "UNIT_long" => "Les commandes comme UNIT_km/h donnent une unité à x, ou \
+    convertissent x s'il a déjà une unité. Mettez-les sur des touches dans \
+    la configuration. Les sommes sont converties dans l'unité de y, les \
+    produits et quotients combinent les unités. Les unités SI, dérivées et \
+    impériales sont connues, les températures seulement en K.",
+
+  "CATALOG" => "Catalogue de conversions",
+  "CATALOG_long" => "Convertir x avec le catalogue: choisir une catégorie \
+    avec A à F (longueur, masse, température, pression, énergie, taille de \
+    données), puis la conversion avec A à F. L'ancien x reste dans LAST x. \
+    Les températures sont converties avec leurs zéros.",
+
+  "LAST_X" => "Rappeler LAST x",
+  "LAST_X_long" => "Mettre x d'avant la dernière conversion du catalogue sur \
+    la pile.",
+
+  "ANGLE" => "Saisie polaire r∠θ",
+  "ANGLE_long" => "Saisir l'angle d'un nombre complexe sous forme polaire \
+    après son module, par exemple 2 ∠ 30 est 2∠30°, en degrés ou en radians \
+    selon DEG.",
+
+  "DEG" => "Degrés ou radians",
+  "DEG_long" => "Basculer le mode d'angle entre radians et degrés. Le mode \
+    s'applique aux fonctions trigonométriques et à la forme polaire des \
+    nombres complexes.",
+
+  "POLAR" => "Affichage polaire",
+  "POLAR_long" => "Basculer l'affichage des nombres complexes entre re i im \
+    et la forme polaire r∠θ, l'angle dans le mode d'angle (voir DEG).",
+
+  "MIXED" => "Nombres fractionnaires",
+  "MIXED_long" => "Basculer l'affichage des fractions au-delà de ±1 entre 19/8 \
+    et le nombre fractionnaire 2 3/8.",
+
+  "FORMAT" => "Format des nombres",
+  "FORMAT_long" => "Parcourir les formats des nombres : groupes de chiffres \
+    avec ', ou une espace et le séparateur décimal . ou ,. D'autres formats \
+    dans la configuration avec group, group_size et decimal_mark.",
+
+  "TO_POLAR" => "Vers polaire: x = r, y = θ",
+  "TO_POLAR_long" => "Séparer le nombre complexe x en son module r dans x et \
+    son angle θ dans y.",
+
+  "TO_RECT" => "Vers cartésien: x = r∠θ",
+  "TO_RECT_long" => "Le nombre complexe de module x et d'angle y, l'inverse \
+    de TO_POLAR.",
+
+  "ARG" => "Angle de x",
+  "ARG_long" => "L'angle de x dans le plan complexe, π ou 180° pour les réels \
+    négatifs.",
+
+  "CONJ" => "Conjugué complexe",
+  "CONJ_long" => "Le conjugué complexe de x: le signe de la partie \
+    imaginaire change.",
+
+  "RE" => "Partie réelle de x",
+  "RE_long" => "La partie réelle du nombre complexe x.",
+
+  "IM" => "Partie imaginaire de x",
+  "IM_long" => "La partie imaginaire du nombre complexe x, 0 pour les réels.",
+
+  "ROUND" => "Arrondir x",
+  "ROUND_long" => "Arrondit x à l'entier le plus proche, les cas à mi-chemin \
+    loin de zéro. \
+    Nombres complexes: parties réelle et imaginaire séparément.",
+
+  "ALT" => "Deuxième et troisième fonction",
+  "ALT_long" => "Beaucoup de touches ont trois fonctions. Une est imprimée en \
+    jaune vif, une en rouge pour la fonction ALT et une en violet pour la \
+    fonction INV. Appuyer une fois pour ALT et deux fois pour INV. Un \
+    indicateur s'affiche en haut à droite.",
+
+  "INV" => "Troisième fonction",
+  "INV_long" => "Passe des fonctions ALT aux fonctions INV imprimées en \
+    violet. Appuyer encore pour revenir aux fonctions de base.",
+
+  "BASE" => "Fonctions de base",
+  "BASE_long" => "Revient aux fonctions de base imprimées en jaune vif.",
+
+  "META" => "Mode suivant",
+  "META_long" => "Passe au mode suivant: des fonctions de base à ALT, de ALT \
+    à INV et de INV aux fonctions de base.",
+
+  "DIGIT" => "Entrer un chiffre",
+  "DIGIT_long" => "Entre le chiffre au curseur, un nouveau nombre pousse la \
+    pile vers le haut. Dans une longue aide 0 défile vers le bas et 2 vers le \
+    haut.",
+
+  "MENU" => "Touche de menu",
+  "MENU_long" => "Choisit dans les menus de DISP, CONF et CATALOG l'élément \
+    au-dessus de la touche. En affichage hexadécimal entre les chiffres \
+    hexadécimaux A à F.",
+
+  "LN" => "Logarithme naturel: ln x",
+  "LN_long" => "Le logarithme de x en base e. Pas encore disponible.",
+
+  "EXP" => "Exponentielle: e ^ x",
+  "EXP_long" => "e puissance x, l'inverse de LN. Pas encore disponible.",
+
+  "PERC" => "Pourcentage: x = y · x / 100",
+  "PERC_long" => "x pour cent de y, y reste. Pas encore disponible.",
+
+  "DPERC" => "Variation en pour cent: Δ%",
+  "DPERC_long" => "La variation de y à x en pour cent de y. Pas encore \
+    disponible.",
+
+  "TO_HMS" => "En heures, minutes, secondes",
+  "TO_HMS_long" => "Convertit les heures x avec décimales en heures, minutes \
+    et secondes. Pas encore disponible.",
+
+  "TO_H" => "En heures",
+  "TO_H_long" => "Convertit les heures, minutes et secondes x en heures \
+    avec décimales, l'inverse de TO_HMS. Pas encore disponible.",
+
+  "H" => "Fonctions hyperboliques",
+  "H_long" => "Le prochain SIN, COS ou TAN et leurs inverses sont les \
+    fonctions hyperboliques. Pas encore disponible.",
+
+  "EDATA" => "Entrer des données: Σ+",
+  "EDATA_long" => "Ajoute x et y aux données statistiques. Pas encore \
+    disponible.",
+
+  "RAND" => "Nombre aléatoire",
+  "RAND_long" => "Un nombre aléatoire entre 0 et 1. Pas encore disponible.",
+
+  "UNDO" => "Annuler",
+  "UNDO_long" => "Annule la dernière commande. Pas encore disponible.",
+
+  "REDO" => "Rétablir",
+  "REDO_long" => "Rétablit la dernière commande annulée. Pas encore \
+    disponible.",
+
+  "MACRO" => "Macros",
+  "MACRO_long" => "Enregistre et rejoue une suite de commandes. Pas encore \
+    disponible.",
+};
+
+// French status and error messages, `{0}`, `{1}` etc. are the parameters
+#[rustfmt::skip]
+pub static MESSAGES: phf::Map<&str, &str> = phf::phf_map! {
+  "lang_name" => "Français",
+
+  // ============================== <-- this is the max. width of 35 columns.
+  "disp_menu" =>        " Std   Fix   Sci   Hex   Brut  Voir",
+  "disp_digit" =>       "Entrer chiffre de précision    Voir",
+  "disp_fix_menu" =>    "Fix: chiffre, ou:  Frac        Voir",
+  "disp_sci_menu" =>    "Sci: chiffre, ou:  Eng   SI    Voir",
+  "disp_hex" =>         "Maj.  Min.                     Voir",
+  "show_x" =>           "x en précision complète :",
+  "disp_set" =>         "Affichage: {0}",
+  "disp_is" =>          "Affichage actuel: {0}",
+  "disp_bad_key" =>     "Mauvaise touche? Affichage: {0}",
+  "disp_std" =>         "standard",
+  "disp_raw" =>         "brut",
+  "disp_fix" =>         "fixe, {0} décimales",
+  "disp_sci" =>         "scientifique, {0} décimales",
+  "disp_eng" =>         "ingénieur, {0} chiffres",
+  "disp_eng_si" =>      "préfixes SI, {0} chiffres",
+  "disp_frac" =>        "fractions, dénominateur ≤ {0}",
+  "disp_full" =>        "précision complète",
+  "disp_hex_upper" =>   "hexa majuscules",
+  "disp_hex_lower" =>   "hexa minuscules",
+
+  "conf_menu" =>        " Base  Alt   Inv   Clav. Reset Lang",
+  "conf_button" =>      "Appuyer la touche {0} à changer",
+  "conf_command" =>     "Remplacer {0} par? Touches: {1}",
+  "conf_key" =>         "Appuyer la touche à changer",
+  "conf_key_command" => "Touche {0} devient? Touches: {1}",
+  "conf_button_set" =>  "Touche changée en {0}",
+  "conf_key_set" =>     "Touche {0} changée en {1}",
+  "conf_reset" =>       "Configuration réinitialisée",
+  "conf_lang" =>        "Langue: {0}",
+  "conf_error" =>       "Erreur: {0}",
+  "conf_mode_button" => "Touche de mode non modifiable",
+  "conf_bad_key" =>     "Mauvaise touche? Rien de changé",
+
+  "catalog_menu" =>     " Long. Masse Temp. Pres. Éner. Oct.",
+  "catalog_done" =>     "{0} converti en {1}",
+  "catalog_bad_key" =>  "Mauvaise touche? x inchangé",
+
+  "err_e_fraction" =>   "Erreur: Pas de 'e' pour fractions",
+  "err_duplicate" =>    "Erreur: '{0}' déjà saisi",
+  "err_misplaced" =>    "Erreur: Pas de '{0}' ici",
+  "err_exp_digits" =>   "Exposant de -999 à 999 seulement",
+  "err_denominator" =>  "Dénominateur de 1 à 999999 seulement",
+  "err_hex_digits" =>   "Hex de 1 à 16 chiffres seulement",
+  "err_numerator" =>    "Numérateur trop grand",
+  "err_mixed" =>        "Pas un nombre fractionnaire",
+  "err_fraction" =>     "Déjà une fraction",
+  "err_fac" =>          "Factorielle d'entiers >= 0 seulement",
+  "err_integers" =>     "Entiers seulement",
+  "exact_on" =>         "Mode exact activé",
+  "exact_off" =>        "Mode exact désactivé",
+  "err_no_exact" =>     "Pas de mode exact sans grands entiers",
+  "decimal_on" =>       "Mode décimal activé",
+  "decimal_off" =>      "Mode décimal désactivé",
+  "err_no_decimal" =>   "Pas de mode décimal disponible",
+  "prec_set" =>         "Précision: {0} chiffres",
+  "err_precision" =>    "Précision de 10 à 15 chiffres seulement",
+  "max_denom_set" =>    "Plus grand dénominateur: {0}",
+  "err_max_denom" =>    "Dénominateur de 2 à 999999 seulement",
+  "err_pm" =>           "Erreur: '±' seulement après un réel",
+  "err_unit" =>         "Erreur: Unité inconnue {0}",
+  "err_units" =>        "Erreur: Unités {0} et {1} incompatibles",
+  "err_unit_long" =>    "Erreur: Unité trop longue",
+  "err_unit_exp" =>     "Erreur: Exposant d'unité au-delà de ±9",
+  "err_unit_plain" =>   "Erreur: Pas d'unité permise, {0}",
+  "err_unit_power" =>   "Erreur: Pas de telle puissance de {0}",
+  "err_catalog_unit" => "Erreur: x a une unité, voir CONVERT",
+  "err_angle" =>        "Erreur: '∠' seulement après un réel",
+  "err_polar_inf" =>    "Erreur: Pas de forme polaire de ∞",
+  "err_paste" =>        "Erreur: Pas un nombre à la colonne {0}",
+  "err_input" =>        "Erreur: Saisie pas un nombre",
+  "deg_on" =>           "Angles en degrés",
+  "deg_off" =>          "Angles en radians",
+  "polar_on" =>         "Affichage polaire activé",
+  "polar_off" =>        "Affichage polaire désactivé",
+  "mixed_on" =>         "Nombres fractionnaires activés",
+  "mixed_off" =>        "Nombres fractionnaires désactivés",
+  "format_set" =>       "Format des nombres {0}",
+};
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -1,12 +1,27 @@
 pub mod calc;
+pub mod catalog;
+pub mod config;
+pub mod context;
 pub mod disp;
+pub mod entry;
+pub mod interval;
+pub mod keymap;
+pub mod lang;
 pub mod native;
 pub mod num;
+pub mod parse;
 pub mod repr;
+pub mod sym;
+pub mod text;
+pub mod unit;
 
 pub use calc::Calc;
 pub use calc::JsCalls;
+pub use config::Config;
+pub use context::Context;
 pub use disp::NumDisplay;
+pub use keymap::Keymap;
+pub use lang::Lang;
 pub use native::Native;
 pub use num::Num;
 pub use repr::Repr;
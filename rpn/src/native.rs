@@ -1,14 +1,31 @@
-use crate::disp::{disp_c, disp_q, disp_r, disp_z, is_disp_as_z};
-use crate::repr::{repr_c, repr_q, repr_r, repr_z};
+#[cfg(feature = "decimal")]
+use crate::context::{is_decimal, precision};
+use crate::context::{is_exact, max_denom};
+#[cfg(feature = "decimal")]
+use crate::disp::disp_d;
+use crate::disp::is_disp_as_z;
+#[cfg(feature = "bigint")]
+use crate::disp::{disp_big_q, disp_big_z};
+use crate::disp::{disp_c, disp_p, disp_q, disp_r, disp_s, disp_u, disp_z};
+#[cfg(feature = "decimal")]
+use crate::repr::repr_d;
+#[cfg(feature = "bigint")]
+use crate::repr::{repr_big_q, repr_big_z};
+use crate::repr::{repr_c, repr_p, repr_q, repr_r, repr_s, repr_u, repr_z};
+use crate::sym::Sym;
 use crate::NumDisplay;
 use crate::Repr;
 use core::fmt;
-use num_complex::ComplexFloat;
+#[cfg(feature = "bigint")]
+use num_bigint::ParseBigIntError;
 use num_traits::cast::FromPrimitive;
+#[cfg(feature = "bigint")]
+use num_traits::Signed;
+use num_traits::ToPrimitive;
 use std::backtrace::Backtrace;
 use std::{
   error::Error,
-  num::{ParseFloatError, ParseIntError},
+  num::{IntErrorKind, ParseFloatError, ParseIntError},
   str::FromStr,
 };
 use types::*;
@@ -39,23 +56,70 @@ use NumDisplay::*;
 /// - Infinities (+oo and -oo) are supported. They are not numbers but useful.
 ///
 /// - Complex infinities (+ioo and -ioo) are supported. Ditto.
+///
+/// - With the feature `bigint` integers beyond Z are big integers, as long as
+///   their repr fits (see `REPR_LEN`). Else they become reals.
+///
+/// - Quotients are limited to numerators and denominators up to 999999 and
+///   become reals beyond. In the exact mode (see `Context::exact`) they become
+///   big quotients instead.
+///
+/// - With the feature `decimal` reals are decimals with 28 digits in the
+///   decimal mode (see `Context::decimal`), as long as they are in the range
+///   of the decimals. There's no need for rounding, 0.1 + 0.2 is exactly 0.3.
+///
+/// - Symbolic terms like `3π/4` or `√2/2` stay exact as long as the
+///   operations allow it, else they become reals (see `Sym`).
+///
+/// - Reals with an uncertainty like `12.3 ± 0.4` propagate the uncertainty
+///   (see `Interval`). Without uncertainty they are reals.
+///
+/// - Reals with a unit like `12.5 km/h` are quantities (see `Quantity`).
+///   Without a dimension they are reals.
 pub mod types {
   /// Corresponds to the set of integers
   pub type Z = i64;
 
+  /// Corresponds to the set of integers beyond Z
+  #[cfg(feature = "bigint")]
+  pub type BigZ = num_bigint::BigInt;
+
+  /// Corresponds to the set of quotients beyond Q
+  #[cfg(feature = "bigint")]
+  pub type BigQ = num_rational::BigRational;
+
   /// Corresponds to the set of reals
   pub type R = f64;
 
+  /// Corresponds to the set of reals with a finite decimal expansion
+  #[cfg(feature = "decimal")]
+  pub type D = rust_decimal::Decimal;
+
   /// Corresponds to the set of quotients
   pub type Q = num_rational::Ratio<Z>;
 
   /// Corresponds to the set of complex numbers
   pub type C = num_complex::Complex<R>;
+
+  /// Corresponds to the rational multiples of π and of square roots
+  pub type S = crate::sym::Sym;
+
+  /// Corresponds to the reals with an uncertainty
+  pub type U = crate::interval::Interval;
+
+  /// Corresponds to the reals with a physical unit
+  pub type P = crate::unit::Quantity;
 }
 
 /// Enum over the native number types in the calculator
-#[derive(Clone, Copy, PartialEq)] #[rustfmt::skip]
-pub enum Native { Integer(Z), Real(R), Quotient(Q), Complex(C) }
+#[derive(Clone, PartialEq)] #[rustfmt::skip]
+pub enum Native {
+  Integer(Z), Real(R), Quotient(Q), Complex(C), Symbolic(S), Interval(U),
+  Quantity(P),
+  #[cfg(feature = "bigint")] BigInteger(BigZ),
+  #[cfg(feature = "bigint")] BigQuotient(BigQ),
+  #[cfg(feature = "decimal")] Decimal(D),
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NativeError(pub String);
@@ -87,6 +151,20 @@ impl From<ParseIntError> for NativeError {
   }
 }
 
+#[cfg(feature = "bigint")]
+impl From<ParseBigIntError> for NativeError {
+  fn from(error: ParseBigIntError) -> Self {
+    Self::from_error(error)
+  }
+}
+
+#[cfg(feature = "decimal")]
+impl From<rust_decimal::Error> for NativeError {
+  fn from(error: rust_decimal::Error) -> Self {
+    Self::from_error(error)
+  }
+}
+
 impl fmt::Debug for Native {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     f.write_str(&format!("‹{}›", self.disp(Raw)))
@@ -105,13 +183,23 @@ impl FromStr for Native {
   /// Parse the internal repr to a native type.
   ///
   /// Parsing is lenient and uses some shortcuts: quotients contain a slash
-  /// like `2/3`, complex numbers the letter `I`, reals `E` and integers none
-  /// these.
+  /// like `2/3`, complex numbers the letter `I` (or `i`), reals `E`, decimals
+  /// start with `D`, symbolic terms `S`, reals with an uncertainty have `±`,
+  /// quantities `_` before the unit and integers none these. Integers beyond
+  /// Z are big integers or else reals.
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     // todo: write a test
 
+    // Quantity has _ before the unit
+    if s.contains('_') {
+      P::parse(s).map_err(|err| NativeError(err.to_string()))?.to_native()
+    }
+    // Interval has ±, also with infinities
+    else if s.contains('±') {
+      Interval(U::parse(s)?)
+    }
     // Positive Infinity
-    if s.starts_with("+o") {
+    else if s.starts_with("+o") {
       Real(f64::INFINITY)
     }
     // Negative Infinity
@@ -126,17 +214,40 @@ impl FromStr for Native {
     else if s.contains("I-o") {
       Complex(C::new(0.0, -f64::INFINITY))
     }
-    // Complex has i
-    else if let Some(pos) = s.find('I') {
+    // Symbolic term starts with S
+    else if let Some(s) = s.strip_prefix('S') {
+      Sym::parse(s)?.to_native()
+    }
+    // Complex has I, or i as typed by the user
+    else if let Some(pos) = s.find(['I', 'i']) {
       let (re_s, im_s) = s.split_at(pos);
       let im_s = &im_s[1..];
       Complex(C::new(re_s.parse()?, im_s.parse()?))
     }
-    // Quotient has /
+    // Quotient has /, big quotients are beyond the limits of Q
     else if let Some(pos_slash) = s.find('/') {
       let (numer_s, denom_s) = s.split_at(pos_slash);
       let denom_s = &denom_s[1..];
-      Quotient(Q::new(numer_s.parse()?, denom_s.parse()?))
+      let q = numer_s.parse().and_then(|n| Ok(Q::new(n, denom_s.parse()?)));
+      match q {
+        #[cfg(feature = "bigint")]
+        Ok(q) if Self::check_q(q).is_ok() => Quotient(q),
+        #[cfg(feature = "bigint")]
+        _ => BigQuotient(BigQ::new(numer_s.parse()?, denom_s.parse()?)),
+        #[cfg(not(feature = "bigint"))]
+        q => Quotient(q?),
+      }
+    }
+    // Decimal starts with D
+    else if let Some(s) = s.strip_prefix('D') {
+      #[cfg(feature = "decimal")]
+      {
+        Decimal(s.parse()?)
+      }
+      #[cfg(not(feature = "decimal"))]
+      {
+        Real(s.parse()?)
+      }
     }
     // Integer in hex
     else if s.find('H') == Some(0) {
@@ -149,7 +260,20 @@ impl FromStr for Native {
     }
     // Anything else try integer
     else {
-      Integer(s.parse()?)
+      match s.parse() {
+        Ok(z) => Integer(z),
+        Err(err) => match err.kind() {
+          #[cfg(feature = "bigint")]
+          IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+            BigInteger(s.parse()?)
+          }
+          #[cfg(not(feature = "bigint"))]
+          IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+            Real(s.parse()?)
+          }
+          _ => return Err(err.into()),
+        },
+      }
     }
     // f64::from_str() might have produced a NaN, check for it.
     .check()
@@ -180,13 +304,68 @@ impl From<C> for Native {
   }
 }
 
+impl From<P> for Native {
+  fn from(p: P) -> Self {
+    Quantity(p)
+  }
+}
+
+impl From<U> for Native {
+  fn from(u: U) -> Self {
+    Interval(u)
+  }
+}
+
+impl From<S> for Native {
+  fn from(s: S) -> Self {
+    Symbolic(s)
+  }
+}
+
+#[cfg(feature = "bigint")]
+impl From<BigZ> for Native {
+  fn from(big_z: BigZ) -> Self {
+    BigInteger(big_z)
+  }
+}
+
+#[cfg(feature = "bigint")]
+impl From<BigQ> for Native {
+  fn from(big_q: BigQ) -> Self {
+    BigQuotient(big_q)
+  }
+}
+
+#[cfg(feature = "decimal")]
+impl From<D> for Native {
+  fn from(d: D) -> Self {
+    Decimal(d)
+  }
+}
+
 impl Native {
+  /// The display in the number format of the context except in Raw and hex,
+  /// see `NumFormat`
   pub fn disp(&self, disp: NumDisplay) -> String {
-    match *self {
-      Integer(z) => disp_z(z, disp),
-      Real(r) => disp_r(r, disp),
-      Quotient(q) => disp_q(q, disp),
-      Complex(c) => disp_c(c, disp),
+    let s = match self {
+      Integer(z) => disp_z(*z, disp),
+      Real(r) => disp_r(*r, disp),
+      Quotient(q) => disp_q(*q, disp),
+      Complex(c) => disp_c(*c, disp),
+      Symbolic(s) => disp_s(*s, disp),
+      Interval(u) => disp_u(*u, disp),
+      Quantity(p) => disp_p(p, disp),
+      #[cfg(feature = "bigint")]
+      BigInteger(big_z) => disp_big_z(big_z, disp),
+      #[cfg(feature = "bigint")]
+      BigQuotient(big_q) => disp_big_q(big_q, disp),
+      #[cfg(feature = "decimal")]
+      Decimal(d) => disp_d(*d, disp),
+    };
+
+    match disp {
+      NumDisplay::Raw | NumDisplay::HexU | NumDisplay::HexL => s,
+      _ => crate::context::get().format.apply(&s),
     }
   }
 
@@ -208,14 +387,25 @@ impl Native {
 
   pub fn check(self) -> Result<Self, NativeError> {
     Ok(match self {
-      Integer(_) => self,
+      Integer(_) | Symbolic(_) => self,
+      #[cfg(feature = "bigint")]
+      BigInteger(_) | BigQuotient(_) => self,
+      #[cfg(feature = "decimal")]
+      Decimal(_) => self,
       Quotient(q) => Quotient(Self::check_q(q)?),
       Real(r) => Real(Self::check_r(r)?),
       Complex(c) => Complex(Self::check_c(c)?),
+      Quantity(p) => Quantity(P::new(Self::check_r(p.value)?, p.unit)),
+      Interval(u) => {
+        Interval(U::new(Self::check_r(u.mid)?, Self::check_r(u.rad)?))
+      }
     })
   }
 
-  // n / 1 -> n,  x + 0i -> x, yyyy.0 -> yyyy, -0.0 -> 0.0, round to 14 digits
+  // n / 1 -> n,  x + 0i -> x, yyyy.0 -> yyyy, -0.0 -> 0.0, round to precision,
+  // big integers fitting Z -> Z, too long for the repr -> R, quotients beyond
+  // the limits -> R or in the exact mode big quotients, in the decimal mode
+  // R -> decimals with the precision and else decimals -> R
   pub fn fix(self) -> Self {
     fn is_denom_1(q: Q) -> bool {
       *q.denom() == 1
@@ -224,14 +414,41 @@ impl Native {
       c.im == 0.0
     }
 
+    let native = match self {
+      Quotient(q) if Self::check_q(q).is_err() && !is_exact() => {
+        Real(q.to_f64().unwrap_or_default())
+      }
+      #[cfg(feature = "bigint")]
+      BigQuotient(big_q) if !is_exact() => {
+        Real(big_q.to_f64().unwrap_or_default())
+      }
+      #[cfg(feature = "decimal")]
+      // Decimals have 28 decimal places, enough for 15 digits from 1e-14
+      Real(r) if is_decimal() && (1e-14..1e28).contains(&r.abs()) => {
+        let prec = precision() as usize - 1;
+        let d = D::from_scientific(&format!("{r:.prec$e}"));
+        d.map_or(self, |d| Decimal(d.normalize()))
+      }
+      #[cfg(feature = "decimal")]
+      Decimal(d) if !is_decimal() => Real(d.to_f64().unwrap_or_default()),
+      _ => self,
+    };
+
     // to string and back
-    let native = self.repr_raw().to_native();
+    let native = native.repr_raw().to_native();
 
     match native {
       Quotient(q) if is_denom_1(q) => Integer(q.to_integer()),
+      #[cfg(feature = "bigint")]
+      BigQuotient(big_q) if big_q.is_integer() => {
+        BigInteger(big_q.to_integer()).fix()
+      }
+      #[cfg(feature = "decimal")]
+      Decimal(d) if d.is_integer() => d.to_i64().map_or(native, Integer),
       Real(r) if is_disp_as_z(r) => Integer(r as Z),
       Complex(c) if is_real(c) && is_disp_as_z(c.re) => Integer(c.re as Z),
       Complex(c) if is_real(c) => Real(c.re),
+      Interval(u) if u.rad == 0.0 => Real(u.mid).fix(),
       _ => native,
     }
   }
@@ -242,6 +459,15 @@ impl Native {
       Real(r) => repr_r(r),
       Quotient(q) => repr_q(q),
       Complex(c) => repr_c(c),
+      Symbolic(s) => repr_s(s),
+      Interval(u) => repr_u(u),
+      Quantity(p) => repr_p(&p),
+      #[cfg(feature = "bigint")]
+      BigInteger(big_z) => repr_big_z(&big_z),
+      #[cfg(feature = "bigint")]
+      BigQuotient(big_q) => repr_big_q(&big_q),
+      #[cfg(feature = "decimal")]
+      Decimal(d) => repr_d(d),
     }
   }
 
@@ -267,32 +493,110 @@ impl Native {
   }
 
   pub fn check_q(q: Q) -> Result<Q, NativeError> {
-    if *q.numer() > 999_999 || *q.denom() > 999_999 {
+    if q.numer().abs() > 999_999 || *q.denom() > 999_999 {
       Err(NativeError("numerator or denominator beyond 999999".into()))
     } else {
       Ok(q)
     }
   }
 
+  /// The best approximation of r by a quotient with a denominator up to
+  /// `max_denom` and a numerator within the limits of Q, with continued
+  /// fractions and their semiconvergents. None if r is beyond the limits.
+  pub fn approx_q(r: R, max_denom: Z) -> Option<Q> {
+    const MAX: Z = 999_999;
+    if !r.is_finite() || r.abs() > MAX as R {
+      return None;
+    }
+
+    // The convergents p/q with (p0, q0) before (p1, q1), of |r| until exact
+    let (mut p0, mut q0, mut p1, mut q1) = (0, 1, 1, 0);
+    let mut x = r.abs();
+    loop {
+      let a = x.floor().min(MAX as R) as Z;
+      let (p2, q2) = (a * p1 + p0, a.saturating_mul(q1) + q0);
+      if p2 > MAX || q2 > max_denom {
+        // The semiconvergent within the limits if nearer than p1/q1
+        let k = (max_denom - q0) / q1;
+        let k = if p1 == 0 { k } else { k.min((MAX - p0) / p1) };
+        let (p, q) = (k * p1 + p0, k * q1 + q0);
+        let error = |p: Z, q: Z| (p as R / q as R - r.abs()).abs();
+        if error(p, q) < error(p1, q1) {
+          (p1, q1) = (p, q);
+        }
+        break;
+      }
+
+      (p0, q0, p1, q1) = (p1, q1, p2, q2);
+      if x == a as R {
+        break;
+      }
+      x = 1.0 / (x - a as R);
+    }
+
+    Some(Q::new(if r < 0.0 { -p1 } else { p1 }, q1))
+  }
+
+  /// The integer rounded, of complex numbers the real part (the modulus is
+  /// `abs()`)
   pub fn as_z(self) -> Z {
     match self.check().expect("invalid number") {
       Integer(z) => z,
       Real(r) => r.round() as Z,
       Quotient(q) => *q.round().numer(),
-      Complex(c) => c.abs().round() as Z,
+      Complex(c) => c.re.round() as Z,
+      Symbolic(s) => s.as_r().round() as Z,
+      Interval(u) => u.mid.round() as Z,
+      Quantity(p) => p.value.round() as Z,
+      #[cfg(feature = "bigint")]
+      BigInteger(big_z) => big_z.to_i64().unwrap_or(if big_z.is_negative() {
+        Z::MIN
+      } else {
+        Z::MAX
+      }),
+      #[cfg(feature = "bigint")]
+      big_q @ BigQuotient(_) => big_q.to_z().as_z(),
+      #[cfg(feature = "decimal")]
+      Decimal(d) => d.round().to_i64().unwrap_or_default(),
     }
   }
 
+  /// To integer, with the feature `bigint` to big integers if beyond Z
   pub fn to_z(self) -> Self {
+    #[cfg(feature = "bigint")]
+    match self {
+      BigInteger(_) => return self,
+      BigQuotient(big_q) => {
+        return BigInteger(big_q.round().to_integer()).fix()
+      }
+      Quotient(_) => (),
+      _ => {
+        let r = self.clone().as_r().round();
+        if r.is_finite() && r.abs() >= Z::MAX as R {
+          return BigInteger(BigZ::from_f64(r).unwrap_or_default()).fix();
+        }
+      }
+    }
+
     Integer(self.as_z()).fix()
   }
 
+  /// The real, of complex numbers the real part
   pub fn as_r(self) -> R {
     match self.check().expect("invalid number") {
       Integer(z) => z as R,
       Real(r) => r,
       Quotient(q) => *q.numer() as f64 / *q.denom() as f64,
-      Complex(c) => c.abs(),
+      Complex(c) => c.re,
+      Symbolic(s) => s.as_r(),
+      Interval(u) => u.mid,
+      Quantity(p) => p.value,
+      #[cfg(feature = "bigint")]
+      BigInteger(big_z) => big_z.to_f64().unwrap_or_default(),
+      #[cfg(feature = "bigint")]
+      BigQuotient(big_q) => big_q.to_f64().unwrap_or_default(),
+      #[cfg(feature = "decimal")]
+      Decimal(d) => d.to_f64().unwrap_or_default(),
     }
   }
 
@@ -300,12 +604,17 @@ impl Native {
     Real(self.as_r()).fix()
   }
 
+  /// The quotient, of complex numbers the real part. Reals are approximated
+  /// with a denominator up to the context's maximum, see `approx_q()`.
   pub fn as_q(self) -> Q {
     match self.check().expect("invalid number") {
       Integer(z) => Q::new(z, 1),
-      Real(r) => Q::from_f64(r).unwrap_or_default(),
       Quotient(q) => q,
-      Complex(c) => Q::from_f64(c.abs()).unwrap_or_default(),
+      native => {
+        let r = native.as_r();
+        let q = Self::approx_q(r, max_denom());
+        q.unwrap_or_else(|| Q::from_f64(r).unwrap_or_default())
+      }
     }
   }
 
@@ -319,6 +628,13 @@ impl Native {
       Real(re) => C { re, im: 0.0 },
       Quotient(q) => C { re: *q.numer() as f64 / *q.denom() as f64, im: 0.0 },
       Complex(c) => c,
+      Symbolic(s) => C { re: s.as_r(), im: 0.0 },
+      Interval(u) => C { re: u.mid, im: 0.0 },
+      Quantity(p) => C { re: p.value, im: 0.0 },
+      #[cfg(feature = "bigint")]
+      big @ (BigInteger(_) | BigQuotient(_)) => C { re: big.as_r(), im: 0.0 },
+      #[cfg(feature = "decimal")]
+      Decimal(d) => C { re: d.to_f64().unwrap_or_default(), im: 0.0 },
     }
   }
 
@@ -328,4 +644,78 @@ impl Native {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+  use super::*;
+
+  // Every display of every native, Hex only for integers and else like Std
+  #[test]
+  fn test_disp_table() {
+    #[track_caller]
+    fn row(s: &str, nums: &str) {
+      let native: Native = s.parse().unwrap();
+      let disps = [Std, Raw, Fix(2), Sci(2), Eng(2), EngSi(2), Frac, HexU];
+      for (disp, num) in disps.into_iter().zip(nums.split(" | ")) {
+        assert_eq!(native.disp(disp), num, "{s} with disp {disp:?}");
+      }
+    }
+
+    row("4700", "4700 | 4700 | 4700 | 4700 | 4.70e3 | 4.70k | 4700 | 125C");
+    row(
+      "-1234.5678",
+      "-1234.5678 | -.12345678E4 | -1234.57 | -1.23e3 | -1.23e3 | -1.23k | \
+        ≈-145679/118 | -1234.5678",
+    );
+    row("1/3", "1/3 | 1/3 | 0.33 | 3.33e-1 | 333e-3 | 333m | 1/3 | 1/3");
+    row(
+      "-19/8",
+      "-19/8 | -19/8 | -2.38 | -2.38e0 | -2.38e0 | -2.38 | -19/8 | -19/8",
+    );
+    row(
+      "1.5i-2.25",
+      "1.5i-2.25 | .15E1I-.225E1 | 1.50i-2.25 | 1.50e0i-2.25e0 | \
+        1.50e0i-2.25e0 | 1.50i-2.25 | 3/2i-9/4 | 1.5i-2.25",
+    );
+    row(
+      "S3π/4",
+      "3π/4 | 3π/4 | 2.36 | 2.36e0 | 2.36e0 | 2.36 | ≈859806/364913 | 3π/4",
+    );
+    row(
+      "12.3±0.4",
+      "12.3 ± 0.4 | .123E2±.4E0 | 12.30 ± 0.40 | 1.23e1 ± 4.00e-1 | \
+        12.3e0 ± 400e-3 | 12.3 ± 400m | 123/10 ± 2/5 | 12.3 ± 0.4",
+    );
+    row(
+      "12.5_km/h",
+      "12.5 km/h | .125E2_km/h | 12.50 km/h | 1.25e1 km/h | 12.5e0 km/h | \
+        12.5 km/h | 25/2 km/h | 12.5 km/h",
+    );
+
+    // Too wide for the stack line, Sci with fewer decimals
+    let wide = "1.234567890e-100i-9.876543211e200";
+    row(
+      "1.2345678901234e-100i-9.8765432109876e200",
+      &format!(
+        "{wide} | .12345678901234E-99I-.98765432109876E201 | 0.00i-9.88e200 | \
+          1.23e-100i-9.88e200 | 123e-102i-988e198 | 123e-102i-988e198 | \
+          {wide} | {wide}"
+      ),
+    );
+    row(
+      "123456.789012345±0.000123456789012",
+      "123456.789012 ± 0.000123456789012 | \
+        .123456789012345E6±.123456789012E-3 | 123456.79 ± 0.00 | \
+        1.23e5 ± 1.23e-4 | 123e3 ± 123e-6 | 123k ± 123µ | \
+        ≈617284/5 ± ≈1/8100 | 123456.789012 ± 0.000123456789012",
+    );
+
+    #[cfg(feature = "bigint")]
+    row(
+      "123456789012345678901234567890",
+      "123'456'789'012'345'678'901'234'567'890 | \
+        123456789012345678901234567890 | \
+        123'456'789'012'345'678'901'234'567'890 | \
+        123'456'789'012'345'678'901'234'567'890 | 123e27 | 123e27 | \
+        123'456'789'012'345'678'901'234'567'890 | 18EE90FF6C373E0EE4E3F0AD2",
+    );
+  }
+}
@@ -1,10 +1,20 @@
+#[cfg(feature = "decimal")]
+use crate::context::is_decimal;
+use crate::context::is_degrees;
+#[cfg(feature = "bigint")]
+use crate::context::is_exact;
 use crate::native::types::*;
 use crate::native::Native::*;
 use crate::native::NativeError;
+use crate::repr::REPR_LEN;
+use crate::sym::Sym;
+use crate::unit::{Unit, UnitError};
 use crate::Native;
 use crate::Repr;
 use num_complex::ComplexFloat;
-use num_traits::Signed;
+use num_integer::Integer;
+use num_traits::{checked_pow, CheckedAdd, CheckedMul, CheckedSub};
+use num_traits::{One, Signed, ToPrimitive, Zero};
 use std::fmt;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -12,72 +22,417 @@ use std::str::FromStr;
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Num(Repr);
 
+// The integers for exact arithmetic: big integers with the feature `bigint`,
+// else Z with the operations failing on overflow
+#[cfg(feature = "bigint")]
+type Int = BigZ;
+
+#[cfg(not(feature = "bigint"))]
+type Int = Z;
+
+// Does the integer fit into the repr?
+fn fits(int: &Int) -> bool {
+  int.to_f64().is_some_and(|r| r.abs().log10() < (REPR_LEN - 2) as R)
+}
+
 impl Num {
   pub const ZERO: Self = Self(Repr::ZERO);
   pub const P_INF: Self = Self(Repr::P_INF);
   pub const M_INF: Self = Self(Repr::M_INF);
+  pub const PI: Self = Self(Repr::PI);
 }
 
 impl Num {
+  /// The integer if x is an integer
+  #[allow(clippy::useless_conversion)] // Int is Z without the feature `bigint`
+  fn as_int(self) -> Option<Int> {
+    match self.0.to_native() {
+      Integer(z) => Some(z.into()),
+      #[cfg(feature = "bigint")]
+      BigInteger(big_z) => Some(big_z),
+      _ => None,
+    }
+  }
+
+  /// The quotient if x is an integer or a quotient, big or not
+  #[cfg(feature = "bigint")]
+  fn as_big_q(self) -> Option<BigQ> {
+    match self.0.to_native() {
+      Integer(z) => Some(BigQ::from_integer(z.into())),
+      Quotient(q) => Some(BigQ::new((*q.numer()).into(), (*q.denom()).into())),
+      BigInteger(big_z) => Some(BigQ::from_integer(big_z)),
+      BigQuotient(big_q) => Some(big_q),
+      _ => None,
+    }
+  }
+
+  /// The decimal if x is an integer or a decimal
+  #[cfg(feature = "decimal")]
+  fn as_d(self) -> Option<D> {
+    match self.0.to_native() {
+      Integer(z) => Some(z.into()),
+      Decimal(d) => Some(d),
+      _ => None,
+    }
+  }
+
+  /// The symbolic term if x is an integer, a quotient or a symbolic term
+  fn as_sym(self) -> Option<Sym> {
+    Sym::from_native(self.0.to_native())
+  }
+
+  /// The interval if x is an interval or else a real without uncertainty
+  fn as_u(self) -> Option<U> {
+    match self.0.to_native() {
+      Interval(u) => Some(u),
+      Complex(_) | Quantity(_) => None,
+      native => Some(U::new(native.as_r(), 0.0)),
+    }
+  }
+
+  /// The function on the interval if x is an interval, see `Interval`
+  fn map_u(self, f: fn(U) -> Option<U>) -> Option<Self> {
+    match self.0.to_native() {
+      Interval(u) => f(u).map(Num::from),
+      _ => None,
+    }
+  }
+
+  /// The function on the intervals if x or the other number is an interval,
+  /// the other one is an interval without uncertainty
+  fn map_u2(self, rhs: Self, f: fn(U, U) -> Option<U>) -> Option<Self> {
+    if !self.is_u() && !rhs.is_u() {
+      return None;
+    }
+    f(self.as_u()?, rhs.as_u()?).map(Num::from)
+  }
+
+  /// The quantity if x is a quantity or else a real without a unit
+  fn as_p(self) -> Option<P> {
+    match self.0.to_native() {
+      Quantity(p) => Some(p),
+      Complex(_) | Interval(_) => None,
+      native => Some(P::plain(native.as_r())),
+    }
+  }
+
+  /// The operation on the quantities if x or the other number is a quantity
+  /// and their units fit, see `Quantity::op()`
+  fn op_p(self, op: &str, rhs: Self) -> Option<Self> {
+    if !self.is_p() && !rhs.is_p() {
+      return None;
+    }
+    self.as_p()?.op(op, rhs.as_p()?).ok().map(Num::from)
+  }
+
+  /// Check the units of x op rhs: adding and subtracting need the same
+  /// dimension, the units of products and quotients must not be too long,
+  /// and powers and roots need plain integers, see `Quantity::op()`
+  pub fn check_units(self, op: &str, rhs: Self) -> Result<(), UnitError> {
+    if !self.is_p() && !rhs.is_p() {
+      return Ok(());
+    }
+
+    match (self.as_p(), rhs.as_p()) {
+      (Some(x), Some(rhs)) => x.op(op, rhs).map(|_| ()),
+      _ => Ok(()),
+    }
+  }
+
+  /// Check that x is a plain number, functions like the sine have no units
+  pub fn check_plain(self) -> Result<(), UnitError> {
+    match self.0.to_native() {
+      Quantity(p) => Err(UnitError::Plain(p.unit.to_string())),
+      _ => Ok(()),
+    }
+  }
+
+  /// Attach the unit to x, or if x has a unit convert it
+  pub fn with_unit(self, unit: &str) -> Result<Self, UnitError> {
+    let unit = Unit::parse(unit)?;
+    match self.0.to_native() {
+      Quantity(p) => Ok(p.convert(&unit)?.into()),
+      native => Ok(P::new(native.as_r(), unit).into()),
+    }
+  }
+
+  /// x in the unit of rhs
+  pub fn convert(self, rhs: Self) -> Result<Self, UnitError> {
+    let (x, rhs) = (self.as_p(), rhs.as_p());
+    match (x, rhs) {
+      (Some(x), Some(rhs)) => Ok(x.convert(&rhs.unit)?.to_native().into()),
+      _ => Ok(self),
+    }
+  }
+
+  /// Is x a big integer?
+  #[cfg(feature = "bigint")]
+  pub fn is_big_z(self) -> bool {
+    matches!(self.0.to_native(), BigInteger(_))
+  }
+
+  /// Is x a big integer? Never without the feature `bigint`
+  #[cfg(not(feature = "bigint"))]
+  pub fn is_big_z(self) -> bool {
+    false
+  }
+
+  /// Is x a big integer or a big quotient?
+  #[cfg(feature = "bigint")]
+  pub fn is_big(self) -> bool {
+    matches!(self.0.to_native(), BigInteger(_) | BigQuotient(_))
+  }
+
+  /// Is x a big integer or a big quotient? Never without the feature `bigint`
+  #[cfg(not(feature = "bigint"))]
+  pub fn is_big(self) -> bool {
+    false
+  }
+
+  /// Exact for integers if the result fits, quantities with their unit to
+  /// the power
   pub fn pow(self, exp: Self) -> Self {
+    if let Some(p) = self.op_p("^", exp) {
+      return p;
+    }
+
+    if let Some(u) = self.map_u2(exp, U::pow) {
+      return u;
+    }
+
+    if let (Some(x), Some(exp)) = (self.as_int(), exp.as_int()) {
+      let log10 = x.to_f64().unwrap_or_default().abs().log10();
+      let exp =
+        exp.to_usize().filter(|&exp| exp as R * log10 < (REPR_LEN - 2) as R);
+      if let Some(z) = exp.and_then(|exp| checked_pow(x, exp)) {
+        return z.into();
+      }
+    }
+
     let x: C = self.into();
     let exp: C = exp.into();
     x.powc(exp).into()
   }
 
+  /// Quantities with the root of their unit, like ∛(8 m³) is 2 m
   pub fn root(self, root: Self) -> Self {
+    if let Some(p) = self.op_p("√", root) {
+      return p;
+    }
+
+    if let Some(u) = self.map_u2(root, |x, root| x.pow(root.recip())) {
+      return u;
+    }
+
     let x: C = self.into();
     let root: C = root.into();
     x.powc(1.0 / root).into()
   }
 
-  pub fn sin(self) -> Self {
+  /// Exact for integers and quotients ≥ 0, for example √8 is 2√2, and
+  /// quantities like √(4 m²) is 2 m
+  pub fn sqrt(self) -> Self {
+    if let Some(p) = self.op_p("√", Num::from_z(2)) {
+      return p;
+    }
+
+    if let Some(u) = self.map_u(|u| u.map(R::sqrt)) {
+      return u;
+    }
+
+    let exact = match self.0.to_native() {
+      Integer(z) => Sym::sqrt(z.into()),
+      Quotient(q) => Sym::sqrt(q),
+      _ => None,
+    };
+    if let Some(sqrt) = exact {
+      return sqrt.into();
+    }
+
     let x: C = self.into();
+    x.sqrt().into()
+  }
+
+  /// Exact for multiples of π/6 and π/4, or of 30° and 45° in degrees
+  pub fn sin(self) -> Self {
+    let x = self.to_rad();
+    if let Some(u) = x.map_u(U::sin) {
+      return u;
+    }
+
+    if let Some(sin) = x.as_sym().and_then(Sym::sin) {
+      return sin.into();
+    }
+
+    let x: C = x.into();
     x.sin().into()
   }
 
   pub fn asin(self) -> Self {
     let x: C = self.into();
-    x.asin().into()
+    let asin =
+      self.map_u(|u| u.map(R::asin)).unwrap_or_else(|| x.asin().into());
+    asin.to_angle()
   }
 
+  /// Exact for multiples of π/6 and π/4, or of 30° and 45° in degrees
   pub fn cos(self) -> Self {
-    let x: C = self.into();
+    let x = self.to_rad();
+    if let Some(u) = x.map_u(U::cos) {
+      return u;
+    }
+
+    if let Some(cos) = x.as_sym().and_then(Sym::cos) {
+      return cos.into();
+    }
+
+    let x: C = x.into();
     x.cos().into()
   }
 
   pub fn acos(self) -> Self {
     let x: C = self.into();
-    x.acos().into()
+    let acos =
+      self.map_u(|u| u.map(R::acos)).unwrap_or_else(|| x.acos().into());
+    acos.to_angle()
   }
 
+  /// Exact for multiples of π/6 and π/4, or of 30° and 45° in degrees
   pub fn tan(self) -> Self {
-    let x: C = self.into();
+    let x = self.to_rad();
+    if let Some(u) = x.map_u(U::tan) {
+      return u;
+    }
+
+    if let Some(tan) = x.as_sym().and_then(Sym::tan) {
+      return tan.into();
+    }
+
+    let x: C = x.into();
     x.tan().into()
   }
 
   pub fn atan(self) -> Self {
     let x: C = self.into();
-    x.atan().into()
+    let atan =
+      self.map_u(|u| u.map(R::atan)).unwrap_or_else(|| x.atan().into());
+    atan.to_angle()
+  }
+
+  // The angle in radians, degrees are exact multiples of π if possible
+  fn to_rad(self) -> Self {
+    if is_degrees() {
+      self.mul_num(Num::PI).div_num(Num::from_z(180))
+    } else {
+      self
+    }
+  }
+
+  // The angle in radians in the angle mode
+  fn to_angle(self) -> Self {
+    if is_degrees() {
+      self.mul_num(Num::from_z(180)).div_num(Num::PI)
+    } else {
+      self
+    }
+  }
+
+  /// The angle of x in the complex plane, π for negative reals
+  pub fn arg(self) -> Self {
+    let x: C = self.into();
+    Num::from_r(x.arg()).to_angle()
+  }
+
+  /// The magnitude and the angle of x
+  pub fn to_polar(self) -> (Self, Self) {
+    (self.abs(), self.arg())
+  }
+
+  /// The complex number with the magnitude and the angle, exact components
+  /// for exact sines and cosines like `2∠90°`
+  pub fn from_polar(r: Self, theta: Self) -> Self {
+    let (re, im) = (r.mul_num(theta.cos()), r.mul_num(theta.sin()));
+    let im: R = im.into();
+    if im == 0.0 {
+      re
+    } else {
+      Num::from_c(re.into(), im)
+    }
   }
 
   pub fn ld(self) -> Self {
+    if let Some(u) = self.map_u(|u| u.map(R::log10)) {
+      return u;
+    }
+
     let x: C = self.into();
     x.log10().into()
   }
 
   pub fn lb(self) -> Self {
+    if let Some(u) = self.map_u(|u| u.map(R::log2)) {
+      return u;
+    }
+
     let x: C = self.into();
     x.log(2.0).into()
   }
 
   pub fn log(self, base: Self) -> Self {
+    if let Some(u) = self.map_u2(base, U::log) {
+      return u;
+    }
+
     let x: C = self.into();
     let base: R = base.into();
     x.log(base).into()
   }
 
-  pub fn decode(s: &str) -> Self {
+  /// The factorial of non-negative integers, exact if the result fits
+  pub fn fac(self) -> Option<Self> {
+    let n = self.as_int()?.to_u64()?;
+
+    // Beyond 170! the factorial is +oo
+    let mut fac = Some(Int::one());
+    let mut r: R = 1.0;
+    for k in 2..=n.min(171) {
+      r *= k as R;
+      let k = Int::from(k as Z);
+      fac = fac.and_then(|fac| CheckedMul::checked_mul(&fac, &k)).filter(fits);
+    }
+
+    Some(match fac {
+      Some(fac) if n <= 171 => fac.into(),
+      _ => r.into(),
+    })
+  }
+
+  /// The modulo y mod x with the sign of x, exact for integers and y mod 0 is
+  /// y
+  pub fn modulo(self, rhs: Self) -> Self {
+    if let (Some(x), Some(rhs)) = (self.as_int(), rhs.as_int()) {
+      return if rhs.is_zero() { x } else { x.mod_floor(&rhs) }.into();
+    }
+
+    if (self.is_q() || rhs.is_q()) && !self.is_big() && !rhs.is_big() {
+      let x: Q = self.into();
+      let rhs: Q = rhs.into();
+      (if rhs.is_zero() { x } else { x - rhs * (x / rhs).floor() }).into()
+    } else {
+      let x: R = self.into();
+      let rhs: R = rhs.into();
+      (if rhs == 0.0 { x } else { x - rhs * (x / rhs).floor() }).into()
+    }
+  }
+
+  /// The greatest common divisor of integers
+  pub fn gcd(self, rhs: Self) -> Option<Self> {
+    Some(self.as_int()?.gcd(&rhs.as_int()?).into())
+  }
+
+  // The number for tests, which panic if it is none
+  #[cfg(test)]
+  fn decode(s: &str) -> Self {
     Num::from_str(s).unwrap_or_else(|err| panic!("{}", err.to_string()))
   }
 }
@@ -94,6 +449,26 @@ impl FromStr for Num {
   type Err = NativeError;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
+    // The polar form r∠θ as typed by the user, the angle in the angle mode
+    if let Some((r, theta)) = s.split_once('∠') {
+      let theta = theta.strip_suffix('°').unwrap_or(theta);
+      let (r, theta): (Num, Num) = (r.parse()?, theta.parse()?);
+      if !R::from(r).is_finite() || !R::from(theta).is_finite() {
+        return Err(NativeError(format!("no polar form of ∞ in `{s}`")));
+      }
+      return Ok(Num::from_polar(r, theta));
+    }
+
+    // The mixed number `2 3/8` as typed by the user, the sign for both parts
+    if let Some((whole, fraction)) = s.split_once(' ') {
+      let negative = whole.starts_with('-');
+      let (whole, fraction) = (whole.parse::<Num>()?, fraction.parse()?);
+      return Ok(match negative {
+        true => whole.sub_num(fraction),
+        false => whole.add_num(fraction),
+      });
+    }
+
     Ok(s.parse::<Native>()?.into())
   }
 }
@@ -104,6 +479,91 @@ impl From<Native> for Num {
   }
 }
 
+#[cfg(feature = "bigint")]
+impl From<BigZ> for Num {
+  fn from(big_z: BigZ) -> Self {
+    Native::from(big_z).into()
+  }
+}
+
+#[cfg(feature = "bigint")]
+impl From<BigQ> for Num {
+  fn from(big_q: BigQ) -> Self {
+    Native::from(big_q).into()
+  }
+}
+
+impl From<P> for Num {
+  fn from(p: P) -> Self {
+    Native::from(p).into()
+  }
+}
+
+impl From<U> for Num {
+  fn from(u: U) -> Self {
+    Native::from(u).into()
+  }
+}
+
+impl From<S> for Num {
+  fn from(s: S) -> Self {
+    Native::from(s).into()
+  }
+}
+
+#[cfg(feature = "decimal")]
+impl From<D> for Num {
+  fn from(d: D) -> Self {
+    Native::from(d).into()
+  }
+}
+
+// 1 / 0 is +oo like for reals
+#[cfg(feature = "decimal")]
+fn recip_d(d: D) -> Num {
+  D::ONE.checked_div(d).map_or(Num::P_INF, Num::from)
+}
+
+// 1 / 0 is +oo like for reals, and with π the reciprocal is a real
+fn recip_s(s: S) -> Num {
+  s.recip().map_or((1.0 / s.as_r()).into(), Num::from)
+}
+
+// The monotonic function on the interval, or on the value if undefined
+fn map_u(u: U, f: fn(R) -> R) -> Num {
+  u.map(f).map_or(f(u.mid).into(), Num::from)
+}
+
+fn fract_u(u: U) -> Num {
+  u.fract().map_or(u.mid.fract().into(), Num::from)
+}
+
+// The function on the real and the imaginary part separately
+fn map_c(c: C, f: fn(R) -> R) -> C {
+  C::new(f(c.re), f(c.im))
+}
+
+// Round half away from zero like for reals
+#[cfg(feature = "decimal")]
+fn round_d(d: D) -> D {
+  d.round_dp_with_strategy(
+    0,
+    rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+  )
+}
+
+// The decimal operation, None on overflow and division by zero
+#[cfg(feature = "decimal")]
+fn checked_d(x: D, op: &str, rhs: D) -> Option<D> {
+  match op {
+    "+" => x.checked_add(rhs),
+    "-" => x.checked_sub(rhs),
+    "*" => x.checked_mul(rhs),
+    "/" => x.checked_div(rhs),
+    _ => None,
+  }
+}
+
 impl Default for Num {
   fn default() -> Self {
     Num::ZERO
@@ -119,8 +579,9 @@ impl fmt::Debug for Num {
 macro_rules! impl_num_methods {
   (
     $(
-      $z:ident $r:ident $q:ident $c:ident $method:ident
-      $z_expr:expr, $r_expr:expr, $q_expr:expr, $c_expr:expr;
+      $z:ident $r:ident $q:ident $c:ident $d:ident $s:ident $u:ident $p:ident
+      $method:ident $z_expr:expr, $r_expr:expr, $q_expr:expr, $c_expr:expr,
+      $d_expr:expr, $s_expr:expr, $u_expr:expr, $p_expr:expr;
     )+
   ) => {
     impl Num {
@@ -131,6 +592,17 @@ macro_rules! impl_num_methods {
             Real($r) => ($r_expr).into(),
             Quotient($q) => ($q_expr).into(),
             Complex($c) => ($c_expr).into(),
+            Symbolic($s) => ($s_expr).into(),
+            Interval($u) => ($u_expr).into(),
+            Quantity($p) => ($p_expr).into(),
+            // Big integers like integers
+            #[cfg(feature = "bigint")]
+            BigInteger($z) => ($z_expr).into(),
+            // Big quotients like quotients
+            #[cfg(feature = "bigint")]
+            BigQuotient($q) => ($q_expr).into(),
+            #[cfg(feature = "decimal")]
+            Decimal($d) => ($d_expr).into(),
           }
         }
       )+
@@ -138,35 +610,108 @@ macro_rules! impl_num_methods {
   }
 }
 
-// The four letters z r q c are match patterns for integers (z), reals (r),
-// quotients (q) and complex (c). After the method name the expressions for
-// each of the four types follow.
+// The eight letters z r q c d s u p are match patterns for integers (z), reals
+// (r), quotients (q), complex (c), decimals (d), symbolic terms (s), intervals
+// (u) and quantities (p). After the method name the expressions for each of
+// the eight types follow.
 impl_num_methods! {
-  // re() -> R { r => r_to_repr(r), q => q2r(q), c => c.re }
-  // im() -> R { _ => 0f64, _ => 0f64, c => c.im }
   // inti() -> Z { r => r2z(r), q => r2z(q2r(q)), c => r2z(c.abs()) }
   // numer() -> Z { r => r2z(r), q => *q.numer(), c => r2z(c.abs()) }
   // denom() -> Z { _ => 1i64, q => *q.denom(), _ => 1i64 }
-  z r q c chs -z, -r, -q, -c;
-  z r q c recip z, 1.0 / r, q.recip(), 1.0 / c;
-  _z r q c frac 0, r.fract(), q.fract(), c.abs().fract();
-  z r q c int z, r.trunc(),   q.trunc(), c.abs().trunc();
-  z r q c abs z.abs(), r.abs(), q.abs(), c.abs();
-  z r q c round z, r.round(), q.round(), c.abs().round();
+  z r q c d s u p chs -z, -r, -q, -c, -d, -s, -u, -p;
+  z r q c d s u p recip z, 1.0 / r, q.recip(), 1.0 / c, recip_d(d),
+    recip_s(s), u.recip(), p.recip();
+  _z r q c d s u p frac 0, r.fract(), q.fract(), map_c(c, R::fract), d.fract(),
+    s.as_r().fract(), fract_u(u), p.map(R::fract);
+  z r q c d s u p int z, r.trunc(),   q.trunc(), map_c(c, R::trunc), d.trunc(),
+    s.as_r().trunc(), map_u(u, R::trunc), p.map(R::trunc);
+  z r q c d s u p abs z.abs(), r.abs(), q.abs(), c.abs(), d.abs(), s.abs(),
+    u.abs(), p.map(R::abs);
+  z r q c d s u p re z, r, q, c.re, d, s, u, p;
+  _z _r _q c _d _s _u _p im 0, 0, 0, c.im, 0, 0, 0, 0;
+  z r q c d s u p conj z, r, q, c.conj(), d, s, u, p;
+  z r q c d s u p round z, r.round(), q.round(), map_c(c, R::round), round_d(d),
+    s.as_r().round(), map_u(u, R::round), p.map(R::round);
 }
 
+// With a checked operation ($checked) integers are calculated exactly if the
+// result fits, else like the other numbers. Symbolic terms stay exact if
+// possible, for example π/2 + π/4 is 3π/4, else they are calculated as reals.
+// Intervals propagate the uncertainty, see `Interval`, and quantities convert
+// and combine their units, see `Quantity` and `check_units()`.
+// In the exact mode integers and quotients are calculated as big quotients and
+// in the decimal mode integers and decimals as decimals.
 macro_rules! impl_binary_ops {
   (
-    $( pub fn $name:ident(self, rhs: Self) -> Self { $op:tt } )+
+    $(
+      pub fn $name:ident(self, rhs: Self) -> Self {
+        $op:tt $( $checked:ident::$method:ident )?
+      }
+    )+
   ) => {
     impl Num {
       $(
         pub fn $name(self, rhs: Self) -> Self {
+          $(
+            if let (Some(x), Some(rhs)) = (self.as_int(), rhs.as_int()) {
+              let z = $checked::$method(&x, &rhs).filter(fits);
+              if let Some(z) = z {
+                return z.into();
+              }
+            }
+          )?
+
+          let is_s = self.is_s() || rhs.is_s();
+          if let (true, Some(x), Some(rhs)) = (is_s, self.as_sym(), rhs.as_sym())
+          {
+            if let Some(s) = x.op(stringify!($op), rhs) {
+              return s.into();
+            }
+          }
+
+          if self.is_p() || rhs.is_p() {
+            if let (Some(x), Some(rhs)) = (self.as_p(), rhs.as_p()) {
+              if let Ok(p) = x.op(stringify!($op), rhs) {
+                return p.into();
+              }
+            }
+          }
+
+          if self.is_u() || rhs.is_u() {
+            if let (Some(x), Some(rhs)) = (self.as_u(), rhs.as_u()) {
+              if let Some(u) = x.op(stringify!($op), rhs) {
+                return u.into();
+              }
+            }
+          }
+
+          #[cfg(feature = "bigint")]
+          if is_exact() {
+            if let (Some(x), Some(rhs)) = (self.as_big_q(), rhs.as_big_q()) {
+              if stringify!($op) != "/" || !rhs.is_zero() {
+                return (x $op rhs).into();
+              }
+            }
+          }
+
+          #[cfg(feature = "decimal")]
+          if is_decimal() {
+            if let (Some(x), Some(rhs)) = (self.as_d(), rhs.as_d()) {
+              if let Some(d) = checked_d(x, stringify!($op), rhs) {
+                return d.into();
+              }
+            }
+          }
+
           if self.is_c() || rhs.is_c() {
             let x: C = self.into();
             let rhs: C = rhs.into();
             (x $op rhs).into()
-          } else if self.is_q() || rhs.is_q() {
+          } else if (self.is_q() || rhs.is_q())
+            && !self.is_big()
+            && !rhs.is_big()
+            && !is_s
+          {
             let x: Q = self.into();
             let rhs: Q = rhs.into();
             (x $op rhs).into()
@@ -182,9 +727,9 @@ macro_rules! impl_binary_ops {
 }
 
 impl_binary_ops! {
-  pub fn add_num(self, rhs: Self) -> Self { + }
-  pub fn sub_num(self, rhs: Self) -> Self { - }
-  pub fn mul_num(self, rhs: Self) -> Self { * }
+  pub fn add_num(self, rhs: Self) -> Self { + CheckedAdd::checked_add }
+  pub fn sub_num(self, rhs: Self) -> Self { - CheckedSub::checked_sub }
+  pub fn mul_num(self, rhs: Self) -> Self { * CheckedMul::checked_mul }
   pub fn div_num(self, rhs: Self) -> Self { / }
 }
 
@@ -239,6 +784,9 @@ impl_is! {
   pub fn is_r(self) -> bool { Real(_) }
   pub fn is_q(self) -> bool { Quotient(_) }
   pub fn is_c(self) -> bool { Complex(_) }
+  pub fn is_s(self) -> bool { Symbolic(_) }
+  pub fn is_u(self) -> bool { Interval(_) }
+  pub fn is_p(self) -> bool { Quantity(_) }
 }
 
 #[cfg(test)]
@@ -252,25 +800,31 @@ mod tests {
 
   #[test]
   fn test_conversions() {
-    assert_eq!(convert("1/2", |n| n.to_r()), "5.0000000000000e-1");
-    assert_eq!(convert("-1/4", |n| n.to_r()), "-2.5000000000000e-1");
+    assert_eq!(convert("1/2", |n| n.to_r()), ".5E0");
+    assert_eq!(convert("-1/4", |n| n.to_r()), "-.25E0");
 
     assert_eq!(convert("8.25", |n| n.to_q()), "33/4");
     assert_eq!(convert("-1.5", |n| n.to_q()), "-3/2");
-    assert_eq!(convert("4/1", |n| n.to_q()), "H0000000000000004");
+    assert_eq!(convert("4/1", |n| n.to_q()), "4");
 
-    assert_eq!(convert("1", |n| n.to_c()), "H0000000000000001");
-    assert_eq!(convert("1/16", |n| n.to_c()), "6.2500000000000e-2");
-    assert_eq!(convert("1i1", |n| n.to_r()), "1.4142135623731e0");
-    assert_eq!(convert("3i3", |n| n.to_z()), "H0000000000000004");
+    assert_eq!(convert("1", |n| n.to_c()), "1");
+    assert_eq!(convert("1/16", |n| n.to_c()), ".625E-1");
+    assert_eq!(convert("1i1", |n| n.to_r()), "1");
+    assert_eq!(convert("3i3", |n| n.to_z()), "3");
+    assert_eq!(convert("1.5i1", |n| n.to_r()), ".15E1");
+    assert_eq!(convert("3.4i3", |n| n.to_z()), "3");
+    assert_eq!(convert("-0.75i3", |n| n.to_q()), "-3/4");
+    assert_eq!(convert("0.1", |n| n.to_q()), "1/10");
+    assert_eq!(convert("0.333333333333333", |n| n.to_q()), "1/3");
+    assert_eq!(convert("3.14159265358979", |n| n.to_q()), "833719/265381");
 
-    assert_eq!(convert("+oo", |n| n.to_z()), "H7fffffffffffffff");
-    assert_eq!(convert("42.499", |n| n.to_z()), "H000000000000002a");
-    assert_eq!(convert("42.5", |n| n.to_z()), "H000000000000002b");
+    assert_eq!(convert("+oo", |n| n.to_z()), "9223372036854775807");
+    assert_eq!(convert("42.499", |n| n.to_z()), "42");
+    assert_eq!(convert("42.5", |n| n.to_z()), "43");
 
-    // Flical's internal precision is 14 decimal digits
-    assert_eq!(convert("44.4999999999994", |n| n.to_z()), "H000000000000002c");
-    assert_eq!(convert("45.4999999999995", |n| n.to_z()), "H000000000000002e");
+    // Flical's internal precision is 15 decimal digits
+    assert_eq!(convert("44.49999999999994", |n| n.to_z()), "44");
+    assert_eq!(convert("45.49999999999995", |n| n.to_z()), "46");
   }
 
   #[test]
@@ -278,11 +832,195 @@ mod tests {
     assert_eq!(Num::decode("+oo").round(), Num::P_INF);
     assert_eq!(Num::from_r(12.5).round(), Num::from_z(13));
     assert_eq!(Num::from_r(-4.9).round(), Num::from_r(-5.0));
+    assert_eq!(Num::from_q(5, 2).round(), Num::from_z(3));
+    assert_eq!(Num::from_q(-7, 3).round(), Num::from_z(-2));
+    assert_eq!(Num::from_c(2.5, -1.4).round(), Num::from_c(3.0, -1.0));
+    assert_eq!(Num::from_c(2.5, 0.4).round(), Num::from_z(3));
+  }
+
+  #[test]
+  fn test_exact_integers() {
+    let z = Num::from_z;
+    assert_eq!(z(3).add_num(z(4)), z(7));
+    assert_eq!(z(3).sub_num(z(4)), z(-1));
+    assert_eq!(z(3).mul_num(z(-4)), z(-12));
+    assert_eq!(z(2).pow(z(10)), z(1024));
 
-    // todo fraction and complex
+    assert_eq!(z(7).modulo(z(-3)), z(-2));
+    assert_eq!(z(-7).modulo(z(3)), z(2));
+    assert_eq!(z(7).modulo(z(0)), z(7));
+    assert_eq!(Num::from_r(7.5).modulo(z(2)), Num::from_r(1.5));
+    assert_eq!(Num::from_q(7, 2).modulo(z(3)), Num::from_q(1, 2));
+
+    assert_eq!(z(12).gcd(z(-18)), Some(z(6)));
+    assert_eq!(Num::from_r(1.5).gcd(z(3)), None);
+
+    assert_eq!(z(0).fac(), Some(z(1)));
+    assert_eq!(z(5).fac(), Some(z(120)));
+    assert_eq!(z(20).fac(), Some(z(2432902008176640000)));
+    assert_eq!(z(-1).fac(), None);
+    assert_eq!(Num::from_r(1.5).fac(), None);
+    assert_eq!(z(1000).fac(), Some(Num::P_INF));
   }
 
-  // todo abs, int, frac
+  #[cfg(not(feature = "bigint"))]
+  #[test]
+  fn test_integer_overflow() {
+    let z = Num::from_z;
+    assert!(z(Z::MAX).add_num(z(1)).is_r());
+    assert!(z(21).fac().unwrap().is_r());
+    assert!(z(2).pow(z(70)).is_r());
+    assert!(Num::decode("99999999999999999999").is_r());
+  }
+
+  #[cfg(feature = "bigint")]
+  #[test]
+  fn test_big_integers() {
+    let z = Num::from_z;
+    let max = z(Z::MAX);
+    let big = max.add_num(z(1));
+    assert!(big.is_big_z());
+    assert_eq!(big.disp(Raw), "9223372036854775808");
+    assert_eq!(big.sub_num(z(1)), max);
+
+    let fac25 = z(25).fac().unwrap();
+    assert!(fac25.is_big_z());
+    assert_eq!(fac25.disp(Raw), "15511210043330985984000000");
+    assert_eq!(fac25.disp(Std), "15'511'210'043'330'985'984'000'000");
+    assert_eq!(fac25.modulo(z(7)), z(0));
+    assert_eq!(fac25.gcd(z(2).pow(z(30))), Some(z(2).pow(z(22))));
+
+    let pow = z(2).pow(z(70));
+    assert_eq!(pow.disp(Raw), "1180591620717411303424");
+    assert_eq!(pow.chs().disp(HexU), "-400000000000000000");
+    assert_eq!(Num::decode("1180591620717411303424"), pow);
+
+    // Beyond the repr they are reals
+    assert!(z(100).fac().unwrap().is_big_z());
+    assert!(z(200).fac().unwrap().is_r());
+    assert!(z(10).pow(z(300)).is_r());
+
+    let to_z = Num::decode("1e30").to_z();
+    assert_eq!(to_z.disp(Raw), "1000000000000000019884624838656");
+  }
+
+  #[test]
+  fn test_quotient_limit() {
+    let q = Num::from_q;
+    let sum = q(1, 999_999).add_num(q(1, 999_998));
+    assert!(sum.is_r());
+  }
+
+  #[cfg(feature = "bigint")]
+  #[test]
+  fn test_exact_quotients() {
+    use crate::context::{Context, ContextGuard};
+
+    let q = Num::from_q;
+    let z = Num::from_z;
+    let context =
+      ContextGuard::new(Context { exact: true, ..Context::default() });
+
+    let sum = q(1, 999_999).add_num(q(1, 999_998));
+    assert!(sum.is_big());
+    assert_eq!(sum.disp(Raw), "1999997/999997000002");
+    assert_eq!(sum.sub_num(q(1, 999_998)), q(1, 999_999));
+    assert_eq!(z(1).div_num(z(3)), q(1, 3));
+    assert_eq!(sum.chs().disp(Std), "-1999997/999997000002");
+    assert_eq!(sum.mul_num(z(999_997_000_002)), z(1_999_997));
+    assert_eq!(z(1).div_num(z(0)), Num::P_INF);
+
+    // Too long for the display, approximately as real
+    let long = z(1).div_num(z(3).pow(z(40)));
+    assert_eq!(long.disp(Raw), "1/12157665459056928801");
+    let long = long.mul_num(long);
+    assert_eq!(long.disp(Std), "≈6.76549570119e-39");
+    assert_eq!(long.disp(HexU), "≈6.76549570119e-39");
+
+    context.install(Context::default());
+    assert!(sum.add_num(z(0)).is_r());
+  }
+
+  #[cfg(feature = "decimal")]
+  #[test]
+  fn test_decimals() {
+    use crate::context::{Context, ContextGuard};
+
+    let d = Num::decode;
+    let context =
+      ContextGuard::new(Context { decimal: true, ..Context::default() });
+
+    let sum = d("0.1").add_num(d("0.2"));
+    assert_eq!(sum, d("0.3"));
+    assert_eq!(format!("{sum:?}"), "Num(Repr D0.3)");
+    assert_eq!(d("0.1").mul_num(Num::from_z(3)), d("0.3"));
+    assert_eq!(d("1.5").add_num(d("1.5")), Num::from_z(3));
+    assert_eq!(
+      Num::from_z(1).div_num(Num::from_z(3)).disp(Std),
+      "0.3333333333333333333333333333"
+    );
+    assert_eq!(d("19.99").mul_num(d("0.075")).disp(Fix(2)), "1.50");
+    assert_eq!(d("2.5").round(), Num::from_z(3));
+    assert_eq!(d("-2.5").round(), Num::from_z(-3));
+    assert_eq!(d("0.25").recip(), Num::from_z(4));
+    assert_eq!(d("-0.25").chs().frac(), d("0.25"));
+    assert_eq!(d("0.5").sin().disp(Raw), "0.479425538604203");
+
+    // Beyond the range of the decimals reals stay
+    assert!(d("1e30").is_r());
+    assert!(d("1.5e-15").is_r());
+    assert_eq!(d("1e20").div_num(d("1e-10")).disp(Std), "1e30");
+
+    context.install(Context::default());
+    assert!(sum.add_num(Num::ZERO).is_r());
+  }
+
+  #[test]
+  fn test_polar() {
+    use crate::context::{Context, ContextGuard};
+
+    let d = Num::decode;
+    assert_eq!(d("3i4").re(), Num::from_z(3));
+    assert_eq!(d("3i4").im(), Num::from_z(4));
+    assert_eq!(d("3i4").conj(), d("3i-4"));
+    assert_eq!(d("2").im(), Num::ZERO);
+    assert_eq!(d("3i4").to_polar().0.disp(Std), "5");
+    assert_eq!(d("-1").arg().disp(Std), "3.14159265359");
+
+    let context =
+      ContextGuard::new(Context { degrees: true, ..Context::default() });
+    assert_eq!(d("2∠90"), d("0i2"));
+    assert_eq!(d("2∠180°"), Num::from_z(-2));
+    assert_eq!(d("1i1").to_polar().1.disp(Std), "45");
+    assert_eq!(Num::from_z(30).sin().disp(Std), "1/2");
+    assert_eq!(d("0.5").asin().disp(Std), "30");
+    let (r, theta) = d("3i4").to_polar();
+    assert_eq!(Num::from_polar(r, theta).disp(Std), "3i4");
+
+    context.install(Context::default());
+    assert_eq!(d("1∠0"), Num::from_z(1));
+  }
+
+  #[test]
+  fn test_abs_int_frac() {
+    #[rustfmt::skip]
+    let table = [
+      // x       abs     int     frac
+      ("-7",     "7",    "-7",   "0"),
+      ("-2.75",  "2.75", "-2",   "-0.75"),
+      ("-11/4",  "11/4", "-2",   "-3/4"),
+      ("3.5i-4", "5.31507290637", "3i-4", "0.5"),
+      ("-1.25i2.5", "2.79508497187", "-1i2", "-0.25i0.5"),
+    ];
+
+    for (x, abs, int, frac) in table {
+      let x = Num::decode(x);
+      assert_eq!(x.abs().disp(Std), abs, "abs {x:?}");
+      assert_eq!(x.int().disp(Std), int, "int {x:?}");
+      assert_eq!(x.frac().disp(Std), frac, "frac {x:?}");
+      assert_eq!(x.int().add_num(x.frac()), x, "int + frac {x:?}");
+    }
+  }
 }
 
 // Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,303 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::context::is_degrees;
+use crate::native::types::*;
+use crate::Num;
+
+/// Where the text isn't a number: the byte range of the offending part, at
+/// the end of the text an empty range, and what was expected there
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+  pub span: Range<usize>,
+  pub expected: &'static str,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let Range { start, end } = self.span;
+    write!(f, "expected {} at {start}..{end}", self.expected)
+  }
+}
+
+impl Error for ParseError {}
+
+/// Parse a number as people write it, for pasted text and scripts. Unlike
+/// the repr syntax of `Num::from_str()` this accepts:
+///
+/// - integers with `_` between digits like `1_000`, in hex like `0x2A` and in
+///   binary like `0b1010`
+/// - reals like `1.5e-3`, quotients like `3/8`, mixed numbers like `2 3/8`
+///   and the infinity `∞`, all of them with a sign
+/// - complex numbers like `3+4i`, `-i` or `2.5i` and in the polar form like
+///   `2∠30°`, without `°` in the angle mode
+pub fn number(text: &str) -> Result<Num, ParseError> {
+  let mut parser = Parser { text, pos: 0 };
+  parser.spaces();
+  let num = parser.complex()?;
+  parser.spaces();
+  match parser.peek() {
+    None => Ok(num),
+    Some(_) => parser.error("the end"),
+  }
+}
+
+struct Parser<'a> {
+  text: &'a str,
+  pos: usize,
+}
+
+impl Parser<'_> {
+  fn peek(&self) -> Option<char> {
+    self.text[self.pos..].chars().next()
+  }
+
+  fn eat(&mut self, prefix: &str) -> bool {
+    let eaten = self.text[self.pos..].starts_with(prefix);
+    if eaten {
+      self.pos += prefix.len();
+    }
+    eaten
+  }
+
+  fn spaces(&mut self) {
+    while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+      self.pos += c.len_utf8();
+    }
+  }
+
+  // The error at the next char or at the end
+  fn error<T>(&self, expected: &'static str) -> Result<T, ParseError> {
+    let end = self.pos + self.peek().map_or(0, char::len_utf8);
+    Err(ParseError { span: self.pos..end, expected })
+  }
+
+  fn error_since<T>(
+    &self,
+    start: usize,
+    expected: &'static str,
+  ) -> Result<T, ParseError> {
+    Err(ParseError { span: start..self.pos, expected })
+  }
+
+  // The sign + or -, true for -
+  fn sign(&mut self) -> bool {
+    !self.eat("+") && self.eat("-")
+  }
+
+  // Digits of the radix with single `_` between them, without the `_`
+  fn digits(&mut self, radix: u32) -> Result<String, ParseError> {
+    let mut digits = String::new();
+    while let Some(c) = self.peek() {
+      if c.is_digit(radix) {
+        digits.push(c);
+        self.pos += 1;
+      } else if c == '_' && !digits.is_empty() {
+        self.pos += 1;
+        if !self.peek().is_some_and(|c| c.is_digit(radix)) {
+          return self.error("a digit after `_`");
+        }
+      } else {
+        break;
+      }
+    }
+    Ok(digits)
+  }
+
+  // A complex number in the rectangular or the polar form, or a real
+  fn complex(&mut self) -> Result<Num, ParseError> {
+    let start = self.pos;
+    let negative = self.sign();
+    if self.eat("i") {
+      return Ok(Num::from_c(0.0, if negative { -1.0 } else { 1.0 }));
+    }
+    self.pos = start;
+
+    let re = self.real()?;
+    let re_end = self.pos;
+    self.spaces();
+    if self.eat("i") {
+      return Ok(Num::from_c(0.0, re.into()));
+    }
+    if self.eat("∠") {
+      // The polar form of ∞ has no direction, and no angle is ∞
+      if !R::from(re).is_finite() {
+        return Err(ParseError { span: start..re_end, expected: "a modulus" });
+      }
+      self.spaces();
+      let theta_start = self.pos;
+      let theta = self.real()?;
+      if !R::from(theta).is_finite() {
+        return self.error_since(theta_start, "an angle");
+      }
+      let degrees = self.eat("°");
+      let theta = match degrees && !is_degrees() {
+        true => theta.mul_num(Num::PI).div_num(Num::from_z(180)),
+        false => theta,
+      };
+      return Ok(Num::from_polar(re, theta));
+    }
+
+    if !matches!(self.peek(), Some('+' | '-')) {
+      return Ok(re);
+    }
+    let negative = self.sign();
+    self.spaces();
+    let im = if self.eat("i") {
+      Num::from_z(1)
+    } else {
+      let im = self.unsigned()?;
+      if !self.eat("i") {
+        return self.error("`i`");
+      }
+      im
+    };
+
+    let im: R = im.into();
+    Ok(Num::from_c(re.into(), if negative { -im } else { im }))
+  }
+
+  // A real with a sign
+  fn real(&mut self) -> Result<Num, ParseError> {
+    let negative = self.sign();
+    let real = self.unsigned()?;
+    Ok(if negative { real.chs() } else { real })
+  }
+
+  // A real without a sign: ∞, hex or binary integers, quotients, mixed
+  // numbers and decimals with an exponent
+  fn unsigned(&mut self) -> Result<Num, ParseError> {
+    let start = self.pos;
+    if self.eat("∞") {
+      return Ok(Num::P_INF);
+    }
+    for (prefix, radix) in [("0x", 16), ("0X", 16), ("0b", 2), ("0B", 2)] {
+      if self.eat(prefix) {
+        let digits = self.digits(radix)?;
+        if digits.is_empty() {
+          return self.error("a digit");
+        }
+        // Parse as unsigned then cast to signed like the repr of hex integers
+        return match u64::from_str_radix(&digits, radix) {
+          Ok(z) => Ok(Num::from_z(z as Z)),
+          Err(_) => self.error_since(start, "at most 64 bits"),
+        };
+      }
+    }
+
+    let int = self.digits(10)?;
+    if !int.is_empty() {
+      if let Some(num) = self.quotient(start, &int)? {
+        return Ok(num);
+      }
+    }
+
+    let frac = if self.eat(".") { self.digits(10)? } else { String::new() };
+    if int.is_empty() && frac.is_empty() {
+      self.pos = start;
+      return self.error("a number");
+    }
+
+    let mut real = format!("{int}.{frac}");
+    if self.eat("e") || self.eat("E") {
+      let negative = self.sign();
+      let exp = self.digits(10)?;
+      if exp.is_empty() {
+        return self.error("the digits of the exponent");
+      }
+      real = format!("{real}e{}{exp}", if negative { "-" } else { "" });
+    }
+
+    // Integers may be big, else like the reals in the context
+    let parsed = match frac.is_empty() && !real.contains('e') {
+      true => int.parse::<Num>(),
+      false => real.parse::<Num>(),
+    };
+    parsed.or_else(|_| self.error_since(start, "a number in range"))
+  }
+
+  // The quotient `n/d` or the mixed number `w n/d` after the digits `int`
+  fn quotient(
+    &mut self,
+    start: usize,
+    int: &str,
+  ) -> Result<Option<Num>, ParseError> {
+    // The numerator after spaces if a mixed number
+    let after_int = self.pos;
+    self.spaces();
+    let numer = if self.pos > after_int { self.digits(10)? } else { "".into() };
+    if self.pos > after_int && numer.is_empty() || !self.eat("/") {
+      self.pos = after_int;
+      return Ok(None);
+    }
+
+    let denom_start = self.pos;
+    let denom = self.digits(10)?;
+    if denom.is_empty() {
+      return self.error("a denominator");
+    }
+    if denom.bytes().all(|b| b == b'0') {
+      return self.error_since(denom_start, "a denominator other than 0");
+    }
+
+    let quotient = match numer.is_empty() {
+      true => format!("{int}/{denom}"),
+      false => format!("{int} {numer}/{denom}"),
+    };
+    match quotient.parse::<Num>() {
+      Ok(num) => Ok(Some(num)),
+      Err(_) => self.error_since(start, "a quotient in range"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::NumDisplay::Std;
+
+  fn disp(text: &str) -> String {
+    number(text).unwrap().disp(Std)
+  }
+
+  fn err(text: &str) -> (Range<usize>, &'static str) {
+    let err = number(text).unwrap_err();
+    (err.span, err.expected)
+  }
+
+  #[test]
+  fn test_number() {
+    assert_eq!(disp("3+4i"), "3i4");
+    assert_eq!(disp(" 3 - i\n"), "3i-1");
+    assert_eq!(disp("-2.5i"), "0i-2.5");
+    assert_eq!(disp("0x2A"), "42");
+    assert_eq!(disp("-0b1010"), "-10");
+    assert_eq!(disp("1_000"), "1000");
+    assert_eq!(disp("2 3/8"), "19/8");
+    assert_eq!(disp("-3/8"), "-3/8");
+    assert_eq!(disp("1.5e-3"), "0.0015");
+    assert_eq!(disp("2."), "2");
+    assert_eq!(disp("∞"), number("+∞").unwrap().disp(Std));
+    assert_eq!(number("-∞"), Ok(Num::M_INF));
+    assert_eq!(number("2∠90°"), Ok(Num::from_c(0.0, 2.0)));
+  }
+
+  #[test]
+  fn test_errors() {
+    assert_eq!(err("3+4j"), (3..4, "`i`"));
+    assert_eq!(err("1__000"), (2..3, "a digit after `_`"));
+    assert_eq!(err("2/0"), (2..3, "a denominator other than 0"));
+    assert_eq!(err("1e"), (2..2, "the digits of the exponent"));
+    assert_eq!(err("0x"), (2..2, "a digit"));
+    assert_eq!(err("0x1_0000_0000_0000_0000"), (0..23, "at most 64 bits"));
+    assert_eq!(err("∠"), (0..3, "a number"));
+    assert_eq!(err("12 km"), (3..4, "the end"));
+    assert_eq!(err("3+"), (2..2, "a number"));
+    assert_eq!(err("1∠∞"), (4..7, "an angle"));
+    assert_eq!(err("∞∠0"), (0..3, "a modulus"));
+    assert_eq!(err("-∞ ∠0"), (0..4, "a modulus"));
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -1,8 +1,14 @@
-use crate::disp::{disp_c, disp_q, disp_r, disp_z};
+#[cfg(feature = "decimal")]
+use crate::disp::disp_d;
+#[cfg(feature = "bigint")]
+use crate::disp::{disp_big_q, disp_big_z};
+use crate::disp::{disp_c, disp_p, disp_q, disp_r, disp_s, disp_u, disp_z};
 use crate::native::types::*;
 use crate::native::Native;
 use crate::NumDisplay::{self, *};
 use core::fmt;
+#[cfg(feature = "bigint")]
+use num_traits::ToPrimitive;
 
 pub fn repr_z(z: Z) -> Repr {
   Repr::from_bytes(disp_z(z, Raw))
@@ -20,11 +26,53 @@ pub fn repr_c(c: C) -> Repr {
   Repr::from_bytes(disp_c(c, Raw))
 }
 
+/// Decimals start with `D` to tell them from integers
+#[cfg(feature = "decimal")]
+pub fn repr_d(d: D) -> Repr {
+  Repr::from_bytes(format!("D{}", disp_d(d, Raw)))
+}
+
+/// Symbolic terms start with `S` to tell them from quotients
+pub fn repr_s(s: S) -> Repr {
+  Repr::from_bytes(format!("S{}", disp_s(s, Raw)))
+}
+
+pub fn repr_u(u: U) -> Repr {
+  Repr::from_bytes(disp_u(u, Raw))
+}
+
+pub fn repr_p(p: &P) -> Repr {
+  Repr::from_bytes(disp_p(p, Raw))
+}
+
+/// Big integers too long for the repr become reals
+#[cfg(feature = "bigint")]
+pub fn repr_big_z(big_z: &BigZ) -> Repr {
+  let s = disp_big_z(big_z, Raw);
+  if s.len() < REPR_LEN {
+    Repr::from_bytes(s)
+  } else {
+    repr_r(big_z.to_f64().unwrap_or_default())
+  }
+}
+
+/// Big quotients too long for the repr become reals
+#[cfg(feature = "bigint")]
+pub fn repr_big_q(big_q: &BigQ) -> Repr {
+  let s = disp_big_q(big_q, Raw);
+  if s.len() < REPR_LEN {
+    Repr::from_bytes(s)
+  } else {
+    repr_r(big_q.to_f64().unwrap_or_default())
+  }
+}
+
 /// The repr is the internal representation of numbers as strings. For
 /// calculations they are converted into one of the native types Z, R, Q or C.
 /// Z and Q use 64-bit integers, R and C use 64-bit IEEE754 numbers (which have
 /// 15.95 decimal digits, see wikipedia.org/wiki/IEEE_754). The calculator
-/// uses 15 decimal digits but displays only 10 decimal digits, because the
+/// uses 15 decimal digits (or less, see `Context::precision`) but displays at
+/// most 12 significant digits in 17 columns (see `disp_std()`), because the
 /// display is only 35 columns wide: -0.123456789e-123i-0.123456789e-123 uses
 /// the whole width of the display!
 ///
@@ -35,18 +83,23 @@ pub fn repr_c(c: C) -> Repr {
 /// Calculator numbers:
 ///
 /// - 64 bit signed integer
-/// - Floating point numbers with 15 decimal digits precision where only 10
-/// are visible and with exponent between -300 and 300 exclusive, and also
-/// infinities +oo and -oo, but not NaNs.
+/// - Floating point numbers with 15 decimal digits precision (or the precision
+///   of the context) where at most 12 are visible and with exponent between
+///   -300 and 300 exclusive, and also infinities +oo and -oo, but not NaNs.
 /// - Reduced quotients with numerator and denominator not more than 6 decimal
-/// digits
+///   digits
+/// - With the feature `decimal` in the decimal mode decimals with 28 digits
 /// - Complex numbers with two floating point numbers as defined abote, one for
-/// the real and the other for the imaginary part and also the four infinities
-/// +oo, -oo, +ioo and -ioo, this means numbers like +oo+i are not supported
+///   the real and the other for the imaginary part and also the four infinities
+///   +oo, -oo, +ioo and -ioo, this means numbers like +oo+i are not supported
+/// - With the feature `bigint` integers beyond 64 bit with up to 255 chars
+///   and in the exact mode quotients beyond 6 digits with up to 255 chars
 ///
 /// The longest repr is for a complex number with a negative part with 15 digits
-/// precision and exponent below -99 both in the real and imaginary part: 41
-/// chars. Example: `"-.123456789012345E-100I-.123456789012345E-100"`.
+/// precision and exponent below -99 both in the real and imaginary part: 45
+/// chars. Example: `"-.123456789012345E-100I-.123456789012345E-100"`. A real
+/// with an uncertainty takes as much, `±` has two bytes. With the feature
+/// `bigint` the repr has room for big integers: 256 chars.
 ///
 /// Note that if a number is element of a subset it will be represented as
 /// being in the subset. Example: 0.0 is mathematically an integer, so it will
@@ -54,19 +107,32 @@ pub fn repr_c(c: C) -> Repr {
 ///
 /// Grammar:
 ///
-/// - Z: decimal number
-/// - Q: Z then `/` then Z (reduced quotients only!)
+/// - Z: decimal number, with the feature `bigint` beyond 64 bit, too
+/// - Q: Z then `/` then Z (reduced quotients only!), with the feature
+///   `bigint` beyond 6 digits, too
 /// - R: scientific notation with leading '.' and at most 15 decimal digits
-/// in mantissa and exponent between -299 and 299 inclusive with letter 'E'
-/// - Z: R then 'I' then R; and also only these complex infinities
-/// `"+.0E+0I+oo"` and `"+.0E+0I-oo"`
+///   (the precision) in mantissa and exponent between -299 and 299 inclusive
+///   with letter 'E'
+/// - D: `D` then a decimal number with `.` and at most 28 digits
+/// - S: `S` then an optional Z, optional `π`, optional `√` then Z and
+///   optional `/` then Z, for example `"S3π/4"` or `"S-√2/2"`
+/// - U: R then `±` then R, for example `".123E2±.4E0"`
+/// - P: R then `_` then a unit of at most 20 bytes (see `Unit`), for example
+///   `".125E2_km/h"`
+/// - C: R then 'I' then R, a zero part is `0`, for example `"-.15E1I.2E-99"`
+///   or `"0I.5E0"`, and also only these complex infinities `"0I+oo"` and
+///   `"0I-oo"`
 ///
 /// Examples: `"0"`, `"42"`, `"-1"`, `"1/2"`, `"-1/3"` `".1E+0"`, `"-.2E-12"`,
 /// `-.42E-3`, `".12345678901234E-299"` and `"+oo"`
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Repr([u8; REPR_LEN]);
 
-pub const REPR_LEN: usize = 41;
+#[cfg(not(feature = "bigint"))]
+pub const REPR_LEN: usize = 45;
+
+#[cfg(feature = "bigint")]
+pub const REPR_LEN: usize = 256;
 
 impl Default for Repr {
   fn default() -> Self {
@@ -88,14 +154,28 @@ impl fmt::Display for Repr {
 }
 
 impl Repr {
-  pub const ZERO: Repr = Repr(*b"0                                        ");
+  pub const ZERO: Repr = Repr::from_static(b"0");
 
-  pub const P_INF: Repr = Repr(*b"+oo                                      ");
+  pub const P_INF: Repr = Repr::from_static(b"+oo");
 
-  pub const M_INF: Repr = Repr(*b"-oo                                      ");
+  pub const M_INF: Repr = Repr::from_static(b"-oo");
+
+  pub const PI: Repr = Repr::from_static("Sπ".as_bytes());
 
   // not a valid representation but used to start a representation.
-  const EMPTY: Repr = Repr(*b"                                         ");
+  const EMPTY: Repr = Repr::from_static(b"");
+
+  // The bytes padded with spaces to REPR_LEN, for the constants
+  const fn from_static(bytes: &[u8]) -> Repr {
+    let mut repr = [b' '; REPR_LEN];
+    let mut i = 0;
+    while i < bytes.len() {
+      repr[i] = bytes[i];
+      i += 1;
+    }
+
+    Repr(repr)
+  }
 
   /// Convert to native. Panic if not a valid representation
   pub fn to_native(&self) -> Native {
@@ -180,7 +260,7 @@ mod tests {
 
   #[test]
   fn test_repr_r_to_str() {
-    let x = 0.1234567890123449; // the last two digits 49 should be truncated
+    let x = 0.1234567890123449; // the last two digits 49 should be rounded
     let ten = 10.0f64;
     let inf = f64::INFINITY;
     let nums = [
@@ -207,23 +287,23 @@ mod tests {
       -inf,
     ];
     let strs = [
-      "1.0000000000000e-1",
-      "1.0000000000000e-1",
-      "3.3333333333333e-1",
-      "1234567890123",
-      "1.2345678901234e12",
-      "12345678901234",
-      "1.2345678901234e14", // todo should be 5 before e instead of 4?
-      "1.2345678901234e10",
-      "1.2345678901234e11",
-      "1.2345678901234e199",
-      "1.2345678901234e299",
+      ".1E0",
+      ".1E0",
+      ".333333333333333E0",
+      ".1234567890123E13",
+      ".12345678901234E13",
+      ".12345678901234E14",
+      ".123456789012345E15",
+      ".123456789012345E11",
+      ".123456789012345E12",
+      ".123456789012345E200",
+      ".123456789012345E300",
       "+oo",
       "-oo",
-      "1.2345678901234e-5",
-      "1.2345678901234e-13",
-      "1.2345678901234e-201",
-      "1.2345678901234e-299",
+      ".123456789012345E-4",
+      ".123456789012345E-12",
+      ".123456789012345E-200",
+      ".123456789012345E-298",
       "0",
       "0",
       "+oo",
@@ -240,7 +320,7 @@ mod tests {
     assert_eq!(Native::q(1, 4).repr().to_str(), "1/4");
     assert_eq!(
       Native::c(0.0, 1.234567890123449).repr().to_str(),
-      "0i1.2345678901234e0"
+      "0I.123456789012345E1"
     );
   }
 
@@ -250,51 +330,44 @@ mod tests {
       Native::r(r).repr().disp(Std)
     }
 
-    assert_eq!(r_std(0.1234567890123), "1.2345678901230e-1");
-    assert_eq!(r_std(0.12345678901234), "1.2345678901234e-1");
-    assert_eq!(r_std(-0.12345678901234), "-1.2345678901234e-1");
-    assert_eq!(r_std(1.2345678901234), "1.2345678901234e0");
-    assert_eq!(r_std(-1.2345678901234), "-1.2345678901234e0");
-
-    assert_eq!(r_std(1.234567890123e15), "1.2345678901230e15");
-    assert_eq!(r_std(1.2345678901234e15), "1.2345678901234e15");
-    assert_eq!(r_std(-1.2345678901234e15), "-1.2345678901234e15");
-    assert_eq!(r_std(1.2345678901234e-2), "1.2345678901234e-2");
-    assert_eq!(r_std(-1.2345678901234e-2), "-1.2345678901234e-2");
-
-    // Round down / up for negative
-    assert_eq!(r_std(0.123456789012341), "1.2345678901234e-1");
-    assert_eq!(r_std(-0.123456789012341), "-1.2345678901234e-1");
-    assert_eq!(r_std(1.23456789012341), "1.2345678901234e0");
-    assert_eq!(r_std(-1.23456789012341), "-1.2345678901234e0");
-
-    // Round to even
-    assert_eq!(r_std(0.123456789012345), "1.2345678901234e-1");
-    assert_eq!(r_std(-0.123456789012345), "-1.2345678901234e-1");
-    assert_eq!(r_std(0.123456789012335), "1.2345678901234e-1");
-    assert_eq!(r_std(-0.123456789012335), "-1.2345678901234e-1");
-
-    // Todo why does this not round to even?
-    assert_eq!(r_std(1.23456789012345), "1.2345678901235e0");
-    assert_eq!(r_std(-1.23456789012345), "-1.2345678901235e0");
-
-    // Round up / down for negative
-    assert_eq!(r_std(0.123456789012351), "1.2345678901235e-1");
-    // assert_eq!(r_std(-0.12345678901251), "-0.123456789013");
-    // assert_eq!(r_std(0.12345678901299), "0.123456789013");
-    // assert_eq!(r_std(-0.12345678901299), "-0.123456789013");
-    // assert_eq!(r_std(1.2345678901251), "1.23456789013");
-    // assert_eq!(r_std(-1.2345678901251), "-1.23456789013");
-    // assert_eq!(r_std(1.2345678901299), "1.23456789013");
-    // assert_eq!(r_std(-1.2345678901299), "-1.23456789013");
-
-    // // Sci to Std
-    // assert_eq!(r_std(1.23456789012e0), "1.23456789012");
-    // assert_eq!(r_std(1.23456789012e11), "123456789012");
-    // assert_eq!(r_std(1.23456789012e-1), "0.123456789012");
-    // assert_eq!(r_std(-1.23456789012e0), "-1.23456789012");
-    // assert_eq!(r_std(-1.23456789012e11), "-123456789012");
-    // assert_eq!(r_std(-1.23456789012e-1), "-0.123456789012");
+    assert_eq!(r_std(0.1234567890123), "0.123456789012");
+    assert_eq!(r_std(0.12345678901234), "0.123456789012");
+    assert_eq!(r_std(-0.12345678901234), "-0.123456789012");
+    assert_eq!(r_std(1.2345678901234), "1.23456789012");
+    assert_eq!(r_std(-1.2345678901234), "-1.23456789012");
+
+    assert_eq!(r_std(1.234567890123e15), "1.23456789012e15");
+    assert_eq!(r_std(1.2345678901234e15), "1.23456789012e15");
+    assert_eq!(r_std(-1.2345678901234e15), "-1.23456789012e15");
+    assert_eq!(r_std(1.2345678901234e-2), "0.0123456789012");
+    assert_eq!(r_std(-1.2345678901234e-2), "-0.0123456789012");
+
+    // The 13th digit rounds the 12th down or up, also for negative numbers
+    assert_eq!(r_std(0.1234567890121), "0.123456789012");
+    assert_eq!(r_std(-0.1234567890121), "-0.123456789012");
+    assert_eq!(r_std(1.234567890124), "1.23456789012");
+    assert_eq!(r_std(-1.234567890124), "-1.23456789012");
+    assert_eq!(r_std(0.1234567890126), "0.123456789013");
+    assert_eq!(r_std(-0.1234567890126), "-0.123456789013");
+    assert_eq!(r_std(1.234567890129), "1.23456789013");
+    assert_eq!(r_std(-1.234567890129), "-1.23456789013");
+    assert_eq!(r_std(9.999999999996), "10");
+
+    // Exact ties round to even, 1234567890.125 and .375 are binary fractions
+    assert_eq!(r_std(1234567890.125), "1234567890.12");
+    assert_eq!(r_std(-1234567890.125), "-1234567890.12");
+    assert_eq!(r_std(1234567890.375), "1234567890.38");
+    assert_eq!(r_std(-1234567890.375), "-1234567890.38");
+    assert_eq!(r_std(123456789012.5), "123456789012");
+    assert_eq!(r_std(123456789013.5), "123456789014");
+
+    // Sci to Std
+    assert_eq!(r_std(1.23456789012e0), "1.23456789012");
+    assert_eq!(r_std(1.23456789012e11), "123456789012");
+    assert_eq!(r_std(1.23456789012e-1), "0.123456789012");
+    assert_eq!(r_std(-1.23456789012e0), "-1.23456789012");
+    assert_eq!(r_std(-1.23456789012e11), "-123456789012");
+    assert_eq!(r_std(-1.23456789012e-1), "-0.123456789012");
   }
 
   #[test]
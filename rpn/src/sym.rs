@@ -0,0 +1,292 @@
+use crate::native::types::*;
+use crate::native::{Native, NativeError};
+use num_integer::Roots;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, Signed, ToPrimitive};
+use std::f64::consts::PI;
+use std::ops::Neg;
+
+/// An exact term q·π·√n with a quotient q, optionally the factor π and a
+/// square-free n. Examples: `3π/4`, `√2/2` and `-π√3`. Operations which can't
+/// stay exact return `None` and the caller decays to reals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sym {
+  pub q: Q,
+  pub pi: bool,
+  pub root: Z,
+}
+
+impl Sym {
+  /// The exact number q·π·√root simplified: squares are moved out of the root,
+  /// without π and root it's a quotient. `None` beyond the limits.
+  pub fn exact(q: Q, pi: bool, root: Z) -> Option<Native> {
+    if root < 0 {
+      return None;
+    }
+
+    // A square like √1000·√1000 stays exact, else the root must be in range
+    // before the search for square factors
+    let (mut q, mut root) = match root.sqrt() {
+      s if s * s == root => (q.checked_mul(&Q::from(s))?, 1),
+      _ if root > 999_999 => return None,
+      _ => (q, root),
+    };
+    let mut f = 2;
+    while f <= root / f {
+      while root % (f * f) == 0 {
+        root /= f * f;
+        q = q.checked_mul(&Q::from(f))?;
+      }
+      f += 1;
+    }
+
+    let q = Native::check_q(q).ok()?;
+    if root == 0 || *q.numer() == 0 {
+      Some(Native::Integer(0))
+    } else if !pi && root == 1 {
+      Some(Native::Quotient(q))
+    } else {
+      Some(Native::Symbolic(Sym { q, pi, root }))
+    }
+  }
+
+  /// The integer or quotient as term
+  pub fn from_q(q: Q) -> Self {
+    Sym { q, pi: false, root: 1 }
+  }
+
+  /// Simplified, or the real if beyond the limits
+  pub fn to_native(self) -> Native {
+    Sym::exact(self.q, self.pi, self.root).unwrap_or(Native::Real(self.as_r()))
+  }
+
+  pub fn as_r(self) -> R {
+    let pi = if self.pi { PI } else { 1.0 };
+    self.q.to_f64().unwrap_or_default() * pi * (self.root as R).sqrt()
+  }
+
+  /// The operation `+`, `-`, `*` or `/` if the result stays exact
+  pub fn op(self, op: &str, rhs: Self) -> Option<Native> {
+    match op {
+      "+" => self.add(rhs),
+      "-" => self.add(-rhs),
+      "*" => self.mul(rhs),
+      "/" => self.div(rhs),
+      _ => None,
+    }
+  }
+
+  fn add(self, rhs: Self) -> Option<Native> {
+    if self.pi == rhs.pi && self.root == rhs.root {
+      Sym::exact(self.q.checked_add(&rhs.q)?, self.pi, self.root)
+    } else if *rhs.q.numer() == 0 {
+      Sym::exact(self.q, self.pi, self.root)
+    } else if *self.q.numer() == 0 {
+      Sym::exact(rhs.q, rhs.pi, rhs.root)
+    } else {
+      None
+    }
+  }
+
+  fn mul(self, rhs: Self) -> Option<Native> {
+    if self.pi && rhs.pi {
+      return None;
+    }
+
+    let q = self.q.checked_mul(&rhs.q)?;
+    Sym::exact(q, self.pi || rhs.pi, self.root.checked_mul(rhs.root)?)
+  }
+
+  // √a / √b is √(a·b) / b
+  fn div(self, rhs: Self) -> Option<Native> {
+    if rhs.pi && !self.pi || *rhs.q.numer() == 0 {
+      return None;
+    }
+
+    let q = self.q.checked_div(&rhs.q)?.checked_div(&Q::from(rhs.root))?;
+    let pi = self.pi && !rhs.pi;
+    Sym::exact(q, pi, self.root.checked_mul(rhs.root)?)
+  }
+
+  pub fn abs(self) -> Self {
+    Sym { q: self.q.abs(), ..self }
+  }
+
+  /// Exact without π
+  pub fn recip(self) -> Option<Native> {
+    Sym::from_q(Q::from(1)).div(self)
+  }
+
+  /// The square root of a quotient ≥ 0: √(n/d) is √(n·d) / d
+  pub fn sqrt(q: Q) -> Option<Native> {
+    if *q.numer() < 0 {
+      return None;
+    }
+
+    let (numer, denom) = (*q.numer(), *q.denom());
+    let root = numer.checked_mul(denom)?;
+    if root.sqrt() * root.sqrt() == root {
+      return Some(Native::Quotient(Q::new(root.sqrt(), denom)));
+    }
+
+    Sym::exact(Q::new(1, denom), false, root)
+  }
+
+  /// Exact for multiples of π/6 and π/4
+  pub fn sin(self) -> Option<Native> {
+    // The angle in twelfths of π, modulo 2π
+    let k = self.q.checked_mul(&Q::from(12)).filter(|k| k.is_integer())?;
+    if !self.pi || self.root != 1 {
+      return None;
+    }
+
+    let k = k.to_integer().rem_euclid(24);
+    let (sign, k) = if k < 12 { (1, k) } else { (-1, k - 12) };
+    let (q, root) = match k.min(12 - k) {
+      0 => (0, 1),
+      2 => (1, 1),
+      3 => (1, 2),
+      4 => (1, 3),
+      6 => (2, 1),
+      _ => return None,
+    };
+
+    Sym::exact(Q::new(sign * q, 2), false, root)
+  }
+
+  pub fn cos(self) -> Option<Native> {
+    Sym { q: self.q.checked_add(&Q::new(1, 2))?, ..self }.sin()
+  }
+
+  pub fn tan(self) -> Option<Native> {
+    let sin = Sym::from_native(self.sin()?)?;
+    let cos = Sym::from_native(self.cos()?)?;
+    sin.div(cos)
+  }
+
+  /// The integer, quotient or term as term
+  pub fn from_native(native: Native) -> Option<Self> {
+    match native {
+      Native::Integer(z) => Some(Sym::from_q(Q::from(z))),
+      Native::Quotient(q) => Some(Sym::from_q(q)),
+      Native::Symbolic(sym) => Some(sym),
+      _ => None,
+    }
+  }
+
+  /// Display like `-3π√2/4`, a numerator 1 is omitted
+  pub fn disp(self) -> String {
+    let (numer, denom) = (*self.q.numer(), *self.q.denom());
+    let mut s = String::from(if numer < 0 { "-" } else { "" });
+    if numer.abs() != 1 {
+      s.push_str(&numer.abs().to_string());
+    }
+    if self.pi {
+      s.push('π');
+    }
+    if self.root != 1 {
+      s.push_str(&format!("√{}", self.root));
+    }
+    if denom != 1 {
+      s.push_str(&format!("/{denom}"));
+    }
+
+    s
+  }
+
+  /// Parse the display of `disp()`
+  pub fn parse(s: &str) -> Result<Self, NativeError> {
+    let err = || NativeError(format!("invalid term `{s}`"));
+    let (sign, rest) = match s.strip_prefix('-') {
+      Some(rest) => (-1, rest),
+      None => (1, s),
+    };
+
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let numer = if end == 0 { 1 } else { rest[..end].parse()? };
+    let rest = &rest[end..];
+    let (pi, rest) = match rest.strip_prefix('π') {
+      Some(rest) => (true, rest),
+      None => (false, rest),
+    };
+    let (root, denom) = match rest.split_once('/') {
+      Some((root, denom)) => (root, denom.parse()?),
+      None => (rest, 1),
+    };
+    let root = match root.strip_prefix('√') {
+      Some(root) => root.parse()?,
+      None if root.is_empty() => 1,
+      None => return Err(err()),
+    };
+    if denom == 0 {
+      return Err(err());
+    }
+
+    Ok(Sym { q: Q::new(sign * numer, denom), pi, root })
+  }
+}
+
+impl Neg for Sym {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Sym { q: -self.q, ..self }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sym(s: &str) -> Native {
+    Native::Symbolic(Sym::parse(s).unwrap())
+  }
+
+  #[test]
+  fn test_parse_disp() {
+    for s in ["π", "-π", "3π/4", "√2", "√2/2", "-2√3/3", "π√2", "5π√6/7"]
+    {
+      assert_eq!(Sym::parse(s).unwrap().disp(), s);
+    }
+    assert!(Sym::parse("π2").is_err());
+    assert!(Sym::parse("π/0").is_err());
+  }
+
+  #[test]
+  fn test_new() {
+    assert_eq!(Sym::exact(Q::from(1), false, 8), Some(sym("2√2")));
+    assert_eq!(
+      Sym::exact(Q::new(1, 3), false, 9),
+      Some(Native::Quotient(1.into()))
+    );
+    assert_eq!(Sym::exact(Q::from(0), true, 2), Some(Native::Integer(0)));
+    assert_eq!(Sym::exact(Q::from(1), false, 1_000_003), None);
+  }
+
+  #[test]
+  fn test_sqrt() {
+    assert_eq!(Sym::sqrt(Q::from(2)), Some(sym("√2")));
+    assert_eq!(Sym::sqrt(Q::new(1, 2)), Some(sym("√2/2")));
+    assert_eq!(Sym::sqrt(Q::new(9, 4)), Some(Native::Quotient(Q::new(3, 2))));
+    assert_eq!(Sym::sqrt(Q::from(-2)), None);
+    assert_eq!(Sym::sqrt(Q::from(9223372036854775783)), None);
+    assert_eq!(
+      Sym::sqrt(Q::from(4_000_000)),
+      Some(Native::Quotient(2000.into()))
+    );
+  }
+
+  #[test]
+  fn test_trigonometry() {
+    let pi = |q: Q| Sym { q, pi: true, root: 1 };
+    assert_eq!(pi(Q::new(1, 2)).sin(), Some(Native::Quotient(1.into())));
+    assert_eq!(pi(Q::new(1, 6)).sin(), Some(Native::Quotient(Q::new(1, 2))));
+    assert_eq!(pi(Q::new(3, 4)).sin(), Some(sym("√2/2")));
+    assert_eq!(pi(Q::new(-1, 3)).sin(), Some(sym("-√3/2")));
+    assert_eq!(pi(Q::from(1)).cos(), Some(Native::Quotient((-1).into())));
+    assert_eq!(pi(Q::new(1, 6)).tan(), Some(sym("√3/3")));
+    assert_eq!(pi(Q::new(1, 2)).tan(), None);
+    assert_eq!(pi(Q::new(1, 5)).sin(), None);
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
@@ -0,0 +1,208 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Display width in columns
+pub const WIDTH: usize = 35;
+
+/// Width of a number on the stack display, after the register name
+pub const NUM_WIDTH: usize = WIDTH - 2;
+
+/// The width of the text in columns, wide characters like CJK count two
+pub fn width(s: &str) -> usize {
+  s.width()
+}
+
+/// Wrap the text to lines not wider than WIDTH. Paragraphs are separated by
+/// empty lines. A paragraph with all its lines fitting is preformatted and
+/// kept, the others are word-wrapped. Words wider than WIDTH are hyphenated.
+pub fn wrap(text: &str) -> String {
+  let mut lines = Vec::new();
+  let mut paragraph = Vec::new();
+  for line in text.split('\n').chain([""]) {
+    if !line.trim().is_empty() {
+      paragraph.push(line);
+      continue;
+    }
+
+    if paragraph.iter().all(|line| width(line) <= WIDTH) {
+      lines.extend(paragraph.drain(..).map(String::from));
+    } else {
+      lines.extend(wrap_words(&std::mem::take(&mut paragraph)));
+    }
+    lines.push(line.into());
+  }
+  lines.pop();
+
+  lines.join("\n")
+}
+
+// Greedy word-wrap, a word not fitting the line is broken after a hyphen or
+// hyphenated if it's wider than WIDTH
+fn wrap_words(paragraph: &[&str]) -> Vec<String> {
+  let mut lines = Vec::new();
+  let mut line = String::new();
+  let words = paragraph.iter().flat_map(|line| line.split_whitespace());
+  for word in words {
+    let mut word = word;
+    loop {
+      let n = width(&line);
+      let space = if n > 0 { 1 } else { 0 };
+      if n + space + width(word) <= WIDTH {
+        if n > 0 {
+          line.push(' ');
+        }
+        line.push_str(word);
+        break;
+      }
+
+      let rest = WIDTH.saturating_sub(n + space);
+      let hyphen =
+        word.char_indices().filter(|&(_, c)| c == '-').map(|(i, _)| i);
+      let hyphen = hyphen.take_while(|&i| width(&word[..=i]) <= rest).last();
+      if let Some(i) = hyphen.filter(|&i| i + 1 < word.len()) {
+        if n > 0 {
+          line.push(' ');
+        }
+        line.push_str(&word[..=i]);
+        lines.push(std::mem::take(&mut line));
+        word = &word[i + 1..];
+      } else if n > 0 {
+        lines.push(std::mem::take(&mut line));
+      } else {
+        let (head, tail) = split_at_width(word, WIDTH - 1);
+        lines.push(format!("{head}-"));
+        word = tail;
+      }
+    }
+  }
+  if !line.is_empty() {
+    lines.push(line);
+  }
+
+  lines
+}
+
+// Split the string after at most `columns` columns, at least one char
+fn split_at_width(s: &str, columns: usize) -> (&str, &str) {
+  let mut n = 0;
+  for (i, c) in s.char_indices() {
+    n += c.width().unwrap_or_default();
+    if n > columns && i > 0 {
+      return s.split_at(i);
+    }
+  }
+
+  (s, "")
+}
+
+/// Break a number into lines not wider than WIDTH, if possible after a
+/// separator like the digit grouping or the `i` of complex numbers, but not
+/// within a real like after its point or sign
+pub fn break_number(number: &str) -> String {
+  let is_separator = |c: char| {
+    !c.is_ascii_alphanumeric() && !matches!(c, '.' | '-' | '+') || c == 'i'
+  };
+  let mut lines = Vec::new();
+  let mut rest = number;
+  while width(rest) > WIDTH {
+    let (head, _) = split_at_width(rest, WIDTH);
+    let separator =
+      head.char_indices().skip(1).filter(|&(_, c)| is_separator(c));
+    let i = separator.last().map_or(head.len(), |(i, c)| i + c.len_utf8());
+    lines.push(&rest[..i]);
+    rest = &rest[i..];
+  }
+  lines.push(rest);
+
+  lines.join("\n")
+}
+
+/// Fit the line into `columns` by cutting it with an ellipsis and padding it
+/// with spaces
+pub fn fit(line: &str, columns: usize) -> String {
+  let line = if width(line) > columns {
+    format!("{}…", split_at_width(line, columns - 1).0)
+  } else {
+    line.into()
+  };
+  let n = width(&line);
+
+  format!("{line}{}", " ".repeat(columns.saturating_sub(n)))
+}
+
+/// Like `fit()` but keep the end of the line, for input with the cursor at
+/// the end
+pub fn fit_end(line: &str, columns: usize) -> String {
+  if width(line) <= columns {
+    return fit(line, columns);
+  }
+
+  let mut n = 1;
+  let start = line.char_indices().rev().find_map(|(i, c)| {
+    n += c.width().unwrap_or_default();
+    (n > columns).then_some(i + c.len_utf8())
+  });
+
+  format!("…{}", &line[start.unwrap_or_default()..])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_wrap() {
+    assert_eq!(wrap("a b\nc"), "a b\nc");
+    assert_eq!(wrap("a\n\nb\n"), "a\n\nb\n");
+
+    let text =
+      "The addition operator. Add number x to y. Both numbers are real.";
+    let lines =
+      "The addition operator. Add number x\nto y. Both numbers are real.";
+    assert_eq!(wrap(text), lines);
+
+    // Preformatted paragraphs stay, the others are wrapped
+    let text = format!("  Pre\n    formatted\n\n{}", "word ".repeat(8));
+    let lines =
+      "  Pre\n    formatted\n\nword word word word word word word\nword";
+    assert_eq!(wrap(&text), lines);
+
+    // Hyphenate words too wide and break after a hyphen
+    let long = "x".repeat(40);
+    assert_eq!(wrap(&long), format!("{}-\nxxxxxx", "x".repeat(34)));
+    let text = "Die Umkehrung des Sinus, das Bogenmass-Ergebnis";
+    let lines = "Die Umkehrung des Sinus, das\nBogenmass-Ergebnis";
+    assert_eq!(wrap(text), lines);
+    let text = "Die Umkehrung des Sinus, Bogenmass-Ergebnis";
+    let lines = "Die Umkehrung des Sinus, Bogenmass-\nErgebnis";
+    assert_eq!(wrap(text), lines);
+
+    // Wide characters count two columns
+    let text = "漢字".repeat(9);
+    assert!(wrap(&text).lines().all(|line| width(line) <= WIDTH));
+  }
+
+  #[test]
+  fn test_break_number() {
+    assert_eq!(break_number("-1'234"), "-1'234");
+
+    let number = format!("-{}", "123'".repeat(9));
+    let lines = format!("-{}\n{}", "123'".repeat(8), "123'");
+    assert_eq!(break_number(&number), lines);
+    assert_eq!(break_number(&"9".repeat(40)), "9".repeat(35) + "\n99999");
+    assert_eq!(
+      break_number("0.0731489773641256i-0.17068094718296"),
+      "0.0731489773641256i\n-0.17068094718296"
+    );
+  }
+
+  #[test]
+  fn test_fit() {
+    assert_eq!(fit("abc", 5), "abc  ");
+    assert_eq!(fit("abcdef", 5), "abcd…");
+    assert_eq!(fit("漢字漢字", 5), "漢字…");
+    assert_eq!(fit_end("abc", 5), "abc  ");
+    assert_eq!(fit_end("abcdef", 5), "…cdef");
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+
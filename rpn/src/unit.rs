@@ -0,0 +1,497 @@
+use crate::native::types::*;
+use crate::native::Native;
+use std::error::Error;
+use std::fmt;
+use std::ops::Neg;
+
+/// The dimension as exponents of the SI base units m, kg, s, A, K, mol and cd
+pub type Dim = [i8; 7];
+
+const ONE: Dim = [0, 0, 0, 0, 0, 0, 0];
+const LENGTH: Dim = [1, 0, 0, 0, 0, 0, 0];
+const MASS: Dim = [0, 1, 0, 0, 0, 0, 0];
+const TIME: Dim = [0, 0, 1, 0, 0, 0, 0];
+const CURRENT: Dim = [0, 0, 0, 1, 0, 0, 0];
+const TEMPERATURE: Dim = [0, 0, 0, 0, 1, 0, 0];
+const AMOUNT: Dim = [0, 0, 0, 0, 0, 1, 0];
+const LUMINOSITY: Dim = [0, 0, 0, 0, 0, 0, 1];
+const AREA: Dim = [2, 0, 0, 0, 0, 0, 0];
+const VOLUME: Dim = [3, 0, 0, 0, 0, 0, 0];
+const SPEED: Dim = [1, 0, -1, 0, 0, 0, 0];
+const FREQUENCY: Dim = [0, 0, -1, 0, 0, 0, 0];
+const FORCE: Dim = [1, 1, -2, 0, 0, 0, 0];
+const PRESSURE: Dim = [-1, 1, -2, 0, 0, 0, 0];
+const ENERGY: Dim = [2, 1, -2, 0, 0, 0, 0];
+const POWER: Dim = [2, 1, -3, 0, 0, 0, 0];
+const CHARGE: Dim = [0, 0, 1, 1, 0, 0, 0];
+const VOLTAGE: Dim = [2, 1, -3, -1, 0, 0, 0];
+const RESISTANCE: Dim = [2, 1, -3, -2, 0, 0, 0];
+
+/// The units with their factor to the SI base units and their dimension.
+/// Temperatures are in kelvin only, because °C and °F have an offset.
+#[rustfmt::skip]
+pub static UNITS: phf::Map<&'static str, (R, Dim)> = phf::phf_map! {
+  // SI base units
+  "m" => (1.0, LENGTH), "kg" => (1.0, MASS), "s" => (1.0, TIME),
+  "A" => (1.0, CURRENT), "K" => (1.0, TEMPERATURE), "mol" => (1.0, AMOUNT),
+  "cd" => (1.0, LUMINOSITY),
+
+  // Prefixed and other metric units
+  "km" => (1e3, LENGTH), "cm" => (1e-2, LENGTH), "mm" => (1e-3, LENGTH),
+  "µm" => (1e-6, LENGTH), "nm" => (1e-9, LENGTH),
+  "g" => (1e-3, MASS), "mg" => (1e-6, MASS), "t" => (1e3, MASS),
+  "ms" => (1e-3, TIME), "µs" => (1e-6, TIME), "ns" => (1e-9, TIME),
+  "min" => (60.0, TIME), "h" => (3600.0, TIME), "d" => (86400.0, TIME),
+  "mA" => (1e-3, CURRENT),
+  "ha" => (1e4, AREA), "L" => (1e-3, VOLUME), "mL" => (1e-6, VOLUME),
+
+  // SI derived units
+  "Hz" => (1.0, FREQUENCY), "N" => (1.0, FORCE), "kN" => (1e3, FORCE),
+  "Pa" => (1.0, PRESSURE), "kPa" => (1e3, PRESSURE),
+  "MPa" => (1e6, PRESSURE), "bar" => (1e5, PRESSURE),
+  "J" => (1.0, ENERGY), "kJ" => (1e3, ENERGY), "kWh" => (3.6e6, ENERGY),
+  "cal" => (4.184, ENERGY), "kcal" => (4184.0, ENERGY),
+  "eV" => (1.602176634e-19, ENERGY),
+  "W" => (1.0, POWER), "kW" => (1e3, POWER),
+  "C" => (1.0, CHARGE), "V" => (1.0, VOLTAGE), "Ω" => (1.0, RESISTANCE),
+
+  // Imperial and US customary units
+  "in" => (0.0254, LENGTH), "ft" => (0.3048, LENGTH), "yd" => (0.9144, LENGTH),
+  "mi" => (1609.344, LENGTH), "nmi" => (1852.0, LENGTH),
+  "oz" => (0.028349523125, MASS), "lb" => (0.45359237, MASS),
+  "acre" => (4046.8564224, AREA), "gal" => (3.785411784e-3, VOLUME),
+  "mph" => (0.44704, SPEED), "kn" => (1852.0 / 3600.0, SPEED),
+  "lbf" => (4.4482216152605, FORCE), "psi" => (6894.757293168361, PRESSURE),
+  "BTU" => (1055.05585262, ENERGY), "hp" => (745.6998715822702, POWER),
+};
+
+/// The longest unit, so that a quantity fits into the repr
+pub const UNIT_LEN: usize = 20;
+
+/// The largest exponent of a unit, like `m⁹` or `1/s⁹`
+pub const MAX_EXP: i8 = 9;
+
+const SUPERSCRIPTS: [char; 10] =
+  ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+#[derive(Clone, Debug, PartialEq, Eq)] #[rustfmt::skip]
+pub enum UnitError {
+  Unknown(String), Mismatch(String, String), TooLong, Overflow, Plain(String),
+  Power(String),
+}
+
+impl fmt::Display for UnitError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      UnitError::Unknown(unit) => write!(f, "unknown unit `{unit}`"),
+      UnitError::Mismatch(a, b) => write!(f, "units `{a}` and `{b}` differ"),
+      UnitError::TooLong => write!(f, "unit too long"),
+      UnitError::Overflow => write!(f, "unit exponent beyond ±{MAX_EXP}"),
+      UnitError::Plain(unit) => write!(f, "unit `{unit}` not allowed"),
+      UnitError::Power(unit) => write!(f, "no such power of `{unit}`"),
+    }
+  }
+}
+
+impl Error for UnitError {}
+
+/// A product of units of the unit table with exponents, like `km/h` or
+/// `kg·m/s²`. The empty unit is a plain number.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Unit(Vec<(&'static str, i8)>);
+
+impl Unit {
+  /// Parse units like `km/h`, `m/s^2`, `kg*m/s²` or `1/s`: units of the table
+  /// with optional exponents up to ±9, all after `/` divide.
+  pub fn parse(s: &str) -> Result<Self, UnitError> {
+    let unknown = || UnitError::Unknown(s.into());
+    let (numer, denom) = s.split_once('/').unwrap_or((s, ""));
+    let mut unit = Unit::default();
+
+    for (part, sign) in [(numer, 1), (denom, -1)] {
+      for factor in part.split(['·', '*']).filter(|f| !f.is_empty()) {
+        if factor == "1" {
+          continue;
+        }
+        let pos =
+          factor.find(|c| c == '^' || SUPERSCRIPTS.contains(&c) || c == '⁻');
+        let (symbol, exp) = factor.split_at(pos.unwrap_or(factor.len()));
+        let symbol = UNITS.get_key(symbol).ok_or_else(unknown)?;
+        let exp = check_exp(parse_exp(exp).ok_or_else(unknown)?)?;
+        unit = unit.mul(&Unit(vec![(symbol, sign * exp)]))?;
+      }
+    }
+
+    if unit.to_string().len() > UNIT_LEN {
+      return Err(UnitError::TooLong);
+    }
+
+    Ok(unit)
+  }
+
+  /// The factor to the SI base units
+  pub fn factor(&self) -> R {
+    self
+      .0
+      .iter()
+      .map(|&(symbol, exp)| UNITS[symbol].0.powi(exp as i32))
+      .product()
+  }
+
+  pub fn dim(&self) -> Result<Dim, UnitError> {
+    let mut dim = ONE;
+    for &(symbol, exp) in &self.0 {
+      for (d, unit_d) in dim.iter_mut().zip(UNITS[symbol].1) {
+        let exp = exp.checked_mul(unit_d).and_then(|exp| d.checked_add(exp));
+        *d = exp.ok_or(UnitError::Overflow)?;
+      }
+    }
+
+    Ok(dim)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// The product, the exponents of the same units add up to at most ±9
+  pub fn mul(&self, rhs: &Unit) -> Result<Unit, UnitError> {
+    let mut unit = self.clone();
+    for &(symbol, exp) in &rhs.0 {
+      match unit.0.iter_mut().find(|(s, _)| *s == symbol) {
+        Some((_, e)) => {
+          *e =
+            e.checked_add(exp).ok_or(UnitError::Overflow).and_then(check_exp)?
+        }
+        None => unit.0.push((symbol, exp)),
+      }
+    }
+    unit.0.retain(|&(_, exp)| exp != 0);
+
+    Ok(unit)
+  }
+
+  /// The power, the exponents multiply to at most ±9
+  pub fn pow(&self, exp: i8) -> Result<Unit, UnitError> {
+    let mut unit = Unit::default();
+    for &(symbol, e) in &self.0 {
+      let e =
+        e.checked_mul(exp).ok_or(UnitError::Overflow).and_then(check_exp)?;
+      unit.0.push((symbol, e));
+    }
+    unit.0.retain(|&(_, exp)| exp != 0);
+
+    Ok(unit)
+  }
+
+  /// The root if all exponents are multiples of the root, like √(m²/s²)
+  pub fn root(&self, root: i8) -> Result<Unit, UnitError> {
+    if root == 0 || self.0.iter().any(|&(_, exp)| exp % root != 0) {
+      return Err(UnitError::Power(self.to_string()));
+    }
+
+    Ok(Unit(self.0.iter().map(|&(symbol, exp)| (symbol, exp / root)).collect()))
+  }
+
+  pub fn recip(&self) -> Unit {
+    Unit(self.0.iter().map(|&(symbol, exp)| (symbol, -exp)).collect())
+  }
+}
+
+// The exponent as `^-2` or as superscripts `⁻²`, without one it's 1
+fn parse_exp(s: &str) -> Option<i8> {
+  if s.is_empty() {
+    return Some(1);
+  }
+  if let Some(exp) = s.strip_prefix('^') {
+    return exp.parse().ok();
+  }
+
+  let (sign, digits) = match s.strip_prefix('⁻') {
+    Some(digits) => (-1, digits),
+    None => (1, s),
+  };
+  let mut exp: Option<i8> = None;
+  for c in digits.chars() {
+    let digit = SUPERSCRIPTS.iter().position(|&s| s == c)? as i8;
+    exp = Some(exp.unwrap_or_default().checked_mul(10)?.checked_add(digit)?);
+  }
+
+  exp.map(|exp| sign * exp)
+}
+
+// The exponent if it's not beyond ±9
+fn check_exp(exp: i8) -> Result<i8, UnitError> {
+  match (-MAX_EXP..=MAX_EXP).contains(&exp) {
+    true => Ok(exp),
+    false => Err(UnitError::Overflow),
+  }
+}
+
+fn superscript(exp: u8) -> String {
+  if exp == 1 {
+    return String::new();
+  }
+
+  exp
+    .to_string()
+    .chars()
+    .map(|c| match c {
+      '-' => '⁻',
+      c => SUPERSCRIPTS[c as usize - '0' as usize],
+    })
+    .collect()
+}
+
+impl fmt::Display for Unit {
+  /// Like `kg·m/s²`, and `1/s` without a numerator
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let join = |sign: i8| {
+      let factors = self.0.iter().filter(|(_, exp)| exp.signum() == sign);
+      let factors = factors.map(|&(symbol, exp)| {
+        format!("{symbol}{}", superscript(exp.unsigned_abs()))
+      });
+      factors.collect::<Vec<_>>().join("·")
+    };
+
+    let (numer, denom) = (join(1), join(-1));
+    match (numer.is_empty(), denom.is_empty()) {
+      (_, true) => f.write_str(&numer),
+      (true, false) => write!(f, "1/{denom}"),
+      (false, false) => write!(f, "{numer}/{denom}"),
+    }
+  }
+}
+
+/// A real with a unit like `12.5 km/h`. Adding and subtracting converts to
+/// the unit of the first quantity, multiplying and dividing combine the
+/// units. A quantity without a dimension like `km/m` is a real.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantity {
+  pub value: R,
+  pub unit: Unit,
+}
+
+impl Quantity {
+  pub fn new(value: R, unit: Unit) -> Self {
+    Quantity { value, unit }
+  }
+
+  /// A plain number, this means without a unit
+  pub fn plain(value: R) -> Self {
+    Quantity::new(value, Unit::default())
+  }
+
+  /// The quantity in the unit, the dimensions must be the same
+  pub fn convert(self, unit: &Unit) -> Result<Self, UnitError> {
+    if self.unit.dim()? != unit.dim()? {
+      return Err(self.mismatch(unit));
+    }
+
+    let value = self.value * self.unit.factor() / unit.factor();
+    Ok(Quantity::new(value, unit.clone()))
+  }
+
+  /// The operation `+`, `-`, `*`, `/`, the power `^` or the root `√`. Powers
+  /// and roots need a plain integer up to ±9 as rhs.
+  pub fn op(self, op: &str, rhs: Self) -> Result<Native, UnitError> {
+    let quantity = match op {
+      "+" | "-" => {
+        if self.unit.dim()? != rhs.unit.dim()? {
+          return Err(self.mismatch(&rhs.unit));
+        }
+        let rhs = rhs.convert(&self.unit)?;
+        let value = if op == "+" {
+          self.value + rhs.value
+        } else {
+          self.value - rhs.value
+        };
+        Quantity::new(value, self.unit)
+      }
+      "*" => Quantity::new(self.value * rhs.value, self.unit.mul(&rhs.unit)?),
+      "/" => {
+        let unit = self.unit.mul(&rhs.unit.recip())?;
+        Quantity::new(self.value / rhs.value, unit)
+      }
+      "^" => {
+        let exp = self.exp(&rhs)?;
+        Quantity::new(self.value.powi(exp as i32), self.unit.pow(exp)?)
+      }
+      "√" => {
+        let root = self.exp(&rhs)?;
+        // Even roots of negative quantities would be complex
+        if root % 2 == 0 && self.value < 0.0 {
+          return Err(UnitError::Power(self.unit.to_string()));
+        }
+        let value = self.value.abs().powf(1.0 / root as R);
+        Quantity::new(value.copysign(self.value), self.unit.root(root)?)
+      }
+      _ => return Err(self.mismatch(&rhs.unit)),
+    };
+    if quantity.unit.to_string().len() > UNIT_LEN {
+      return Err(UnitError::TooLong);
+    }
+
+    Ok(quantity.to_native())
+  }
+
+  // The exponent of a power or a root, a plain integer up to ±9
+  fn exp(&self, rhs: &Self) -> Result<i8, UnitError> {
+    if !rhs.unit.is_empty() {
+      return Err(UnitError::Plain(rhs.unit.to_string()));
+    }
+    match rhs.value {
+      exp if exp.fract() == 0.0 && exp.abs() <= MAX_EXP as R => Ok(exp as i8),
+      _ => Err(UnitError::Power(self.unit.to_string())),
+    }
+  }
+
+  pub fn map(self, f: fn(R) -> R) -> Self {
+    Quantity::new(f(self.value), self.unit)
+  }
+
+  pub fn recip(self) -> Self {
+    Quantity::new(1.0 / self.value, self.unit.recip())
+  }
+
+  /// Without a dimension a real, else the quantity
+  pub fn to_native(self) -> Native {
+    if self.unit.dim() == Ok(ONE) {
+      Native::Real(self.value * self.unit.factor())
+    } else {
+      Native::Quantity(self)
+    }
+  }
+
+  /// Parse the value and the unit separated by `_`
+  pub fn parse(s: &str) -> Result<Self, UnitError> {
+    let (value, unit) = s.split_once('_').unwrap_or((s, ""));
+    let value = value.parse().map_err(|_| UnitError::Unknown(s.into()))?;
+
+    Ok(Quantity::new(value, Unit::parse(unit)?))
+  }
+
+  fn mismatch(&self, unit: &Unit) -> UnitError {
+    let name =
+      |unit: &Unit| if unit.is_empty() { "1".into() } else { unit.to_string() };
+    UnitError::Mismatch(name(&self.unit), name(unit))
+  }
+}
+
+impl Neg for Quantity {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Quantity { value: -self.value, ..self }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn unit(s: &str) -> Unit {
+    Unit::parse(s).unwrap()
+  }
+
+  fn p(value: R, s: &str) -> Quantity {
+    Quantity::new(value, unit(s))
+  }
+
+  #[test]
+  fn test_parse_display() {
+    assert_eq!(unit("km/h").to_string(), "km/h");
+    assert_eq!(unit("kg*m/s^2").to_string(), "kg·m/s²");
+    assert_eq!(unit("kg·m/s²").dim(), Ok(FORCE));
+    assert_eq!(unit("1/s").to_string(), "1/s");
+    assert_eq!(unit("m^-1").to_string(), "1/m");
+    assert_eq!(unit("m·m/m").to_string(), "m");
+    assert_eq!(unit("m³").dim(), Ok(VOLUME));
+    assert_eq!(
+      Unit::parse("furlong"),
+      Err(UnitError::Unknown("furlong".into()))
+    );
+    assert_eq!(Unit::parse("kg·m²/s³·A·K·mol·cd"), Err(UnitError::TooLong));
+  }
+
+  #[test]
+  fn test_overflow() {
+    assert_eq!(unit("m^9").to_string(), "m⁹");
+    assert_eq!(unit("1/m⁹").to_string(), "1/m⁹");
+    assert_eq!(unit("L^9").dim(), Ok([27, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(Unit::parse("m^-128"), Err(UnitError::Overflow));
+    assert_eq!(Unit::parse("1/m^-128"), Err(UnitError::Overflow));
+    assert_eq!(Unit::parse("L^100"), Err(UnitError::Overflow));
+    assert_eq!(Unit::parse("m⁵·m⁵"), Err(UnitError::Overflow));
+    assert_eq!(unit("m^5").mul(&unit("m^5")), Err(UnitError::Overflow));
+    assert_eq!(unit("m^5").mul(&unit("1/m^5")), Ok(Unit::default()));
+
+    // Dimensions beyond i8 only with exponents beyond ±9
+    let cubes = Unit(vec![("L", 50)]);
+    assert_eq!(cubes.dim(), Err(UnitError::Overflow));
+    assert_eq!(p(1.0, "m^5").op("*", p(1.0, "m^5")), Err(UnitError::Overflow));
+  }
+
+  #[test]
+  fn test_convert() {
+    let close = |p: Quantity, value: R| (p.value - value).abs() < 1e-9;
+    assert!(close(p(1.0, "mi").convert(&unit("km")).unwrap(), 1.609344));
+    assert!(close(
+      p(100.0, "km/h").convert(&unit("mph")).unwrap(),
+      62.1371192237334
+    ));
+    assert!(close(p(1.0, "kWh").convert(&unit("J")).unwrap(), 3.6e6));
+    assert!(close(p(1.0, "N").convert(&unit("kg·m/s²")).unwrap(), 1.0));
+    assert_eq!(
+      p(1.0, "m").convert(&unit("s")),
+      Err(UnitError::Mismatch("m".into(), "s".into()))
+    );
+  }
+
+  #[test]
+  fn test_op() {
+    let q = |native: Native| match native {
+      Native::Quantity(q) => q,
+      native => panic!("no quantity {native:?}"),
+    };
+    let sum = q(p(1.0, "km").op("+", p(500.0, "m")).unwrap());
+    assert_eq!(sum, p(1.5, "km"));
+    let speed = q(p(100.0, "km").op("/", p(2.0, "h")).unwrap());
+    assert_eq!(speed, p(50.0, "km/h"));
+    let distance = q(speed.op("*", p(3.0, "h")).unwrap());
+    assert_eq!(distance, p(150.0, "km"));
+    assert_eq!(p(1.0, "km").op("/", p(1.0, "m")), Ok(Native::Real(1000.0)));
+    assert_eq!(
+      p(1.0, "km").op("+", Quantity::plain(1.0)),
+      Err(UnitError::Mismatch("km".into(), "1".into()))
+    );
+  }
+
+  #[test]
+  fn test_pow_root() {
+    let q = |native: Native| match native {
+      Native::Quantity(q) => q,
+      native => panic!("no quantity {native:?}"),
+    };
+    let plain = Quantity::plain;
+    assert_eq!(q(p(3.0, "m").op("^", plain(2.0)).unwrap()), p(9.0, "m²"));
+    assert_eq!(q(p(2.0, "s").op("^", plain(-1.0)).unwrap()), p(0.5, "1/s"));
+    assert_eq!(p(2.0, "m").op("^", plain(0.0)), Ok(Native::Real(1.0)));
+    assert_eq!(q(p(4.0, "m²/s²").op("√", plain(2.0)).unwrap()), p(2.0, "m/s"));
+    assert_eq!(q(p(-8.0, "m³").op("√", plain(3.0)).unwrap()), p(-2.0, "m"));
+
+    let power = |unit: &str| Err(UnitError::Power(unit.into()));
+    assert_eq!(p(2.0, "m").op("^", plain(0.5)), power("m"));
+    assert_eq!(p(2.0, "m").op("^", plain(10.0)), power("m"));
+    assert_eq!(p(2.0, "m").op("√", plain(2.0)), power("m"));
+    assert_eq!(p(-4.0, "m²").op("√", plain(2.0)), power("m²"));
+    assert_eq!(p(4.0, "m²").op("√", plain(0.0)), power("m²"));
+    assert_eq!(p(2.0, "m^5").op("^", plain(2.0)), Err(UnitError::Overflow));
+    assert_eq!(
+      plain(2.0).op("^", p(2.0, "m")),
+      Err(UnitError::Plain("m".into()))
+    );
+  }
+}
+
+// Copyright see AUTHORS & LICENSE; SPDX-License-Identifier: ISC+